@@ -1,12 +1,29 @@
-use std::sync::Arc;
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    sync::Arc,
+};
 
-use reqwest::{Client, Response};
+use reqwest::Client;
 use thiserror::Error;
+use tokio::sync::{Mutex, RwLock};
 
-use crate::webdynpro::client::{SapSsrClient, SapSsrClientError};
+use crate::{
+    config::USaintConfig,
+    session::{USaintSession, USaintSessionError},
+    webdynpro::{
+        client::{SapSsrClient, SapSsrClientError},
+        document::{DocumentError, WebDynproDocument},
+        element::{
+            sap_table::{SapTable, SapTableRow},
+            Element, ElementError,
+        },
+        event::{client_inspector_notify, SapEvent, SapEventBuilderError, SapEventQueue},
+        page,
+        response::{ResponseError, WebDynproResponse},
+    },
+};
 
 pub mod course_grades;
-pub mod course_schedules;
 
 #[derive(Debug, Error)]
 pub enum ApplicationError {
@@ -14,51 +31,326 @@ pub enum ApplicationError {
     SapSsrClientError(#[from] SapSsrClientError),
     #[error("HTTP 요청 오류: {0}")]
     RequestError(#[from] reqwest::Error),
+    #[error("세션 오류: {0}")]
+    SessionError(#[from] USaintSessionError),
+    #[error("세션이 만료되었고 다시 인증할 수 없습니다.")]
+    SessionExpired,
+    #[error("세션이 만료되었지만 토큰이나 저장된 세션으로 만든 세션이라 다시 로그인할 계정 정보가 없습니다. 새로 로그인하세요.")]
+    SessionNotRenewable,
+    #[error("세션이 만료되어 다시 로그인했지만, 애플리케이션이 첫 화면으로 초기화되어 요청을 보내지 않았습니다. 작업을 처음부터 다시 수행하세요.")]
+    SessionRecovered,
+    #[error("SAP 이벤트 빌더 오류: {0}")]
+    SapEventBuilderError(#[from] SapEventBuilderError),
+    #[error("WebDynpro 응답 해석 오류: {0}")]
+    ResponseError(#[from] ResponseError),
+    #[error("컨트롤 오류: {0}")]
+    ElementError(#[from] ElementError),
+    #[error("화면 갱신 오류: {0}")]
+    DocumentError(#[from] DocumentError),
+    #[error("{id} 테이블의 전체 {expected}개 행 중 {read}개만 읽었습니다.")]
+    IncompleteTable {
+        id: String,
+        expected: u32,
+        read: usize,
+    },
 }
 
 pub struct Application {
     client: Arc<Client>,
-    sap_ssr_client: SapSsrClient,
+    config: USaintConfig,
+    session: Option<Arc<USaintSession>>,
+    app_name: String,
+    sap_ssr_client: RwLock<SapSsrClient>,
+    document: Mutex<WebDynproDocument>,
 }
 
 impl Application {
     // SAP SSR Client 정보 획득
     pub async fn new(client: Arc<Client>, app_name: &str) -> Result<Self, ApplicationError> {
-        let sap_ssr_client = SapSsrClient::new(client.clone(), app_name).await?;
+        Self::with_config(client, USaintConfig::default(), app_name).await
+    }
+
+    /// 주어진 서버 설정으로 애플리케이션을 생성합니다.
+    pub async fn with_config(
+        client: Arc<Client>,
+        config: USaintConfig,
+        app_name: &str,
+    ) -> Result<Self, ApplicationError> {
+        let sap_ssr_client = SapSsrClient::new(client.clone(), &config, app_name).await?;
+        Ok(Application {
+            client,
+            config,
+            session: None,
+            app_name: app_name.to_string(),
+            document: Mutex::new(WebDynproDocument::new(&sap_ssr_client.page)),
+            sap_ssr_client: RwLock::new(sap_ssr_client),
+        })
+    }
+
+    /// 세션과 함께 애플리케이션을 생성합니다.
+    /// 세션이 만료되면 세션의 계정 정보로 다시 로그인하고 SAP SSR Client를 다시 초기화합니다.
+    pub async fn with_session(
+        session: Arc<USaintSession>,
+        app_name: &str,
+    ) -> Result<Self, ApplicationError> {
+        let client = session.client.clone();
+        let config = session.config().clone();
+
+        let sap_ssr_client = match SapSsrClient::new(client.clone(), &config, app_name).await {
+            Err(SapSsrClientError::SessionExpired { .. }) => {
+                session.reauthenticate().await?;
+                SapSsrClient::new(client.clone(), &config, app_name).await?
+            }
+            result => result?,
+        };
+
         Ok(Application {
             client,
-            sap_ssr_client,
+            config,
+            session: Some(session),
+            app_name: app_name.to_string(),
+            document: Mutex::new(WebDynproDocument::new(&sap_ssr_client.page)),
+            sap_ssr_client: RwLock::new(sap_ssr_client),
         })
     }
 
+    /// 애플리케이션 이름을 반환합니다.
+    pub fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    /// 화면 상태를 바꾸지 않는 ClientInspector 알림을 보내 서버의 애플리케이션 세션이 만료되지 않도록 합니다.
+    pub async fn keep_alive(&self) -> Result<(), ApplicationError> {
+        let sap_event_queue = client_inspector_notify().to_string();
+
+        self.send_request(Some(&sap_event_queue)).await?;
+
+        Ok(())
+    }
+
+    /// 현재 화면 상태를 담은 문서를 반환합니다.
+    /// 지금까지 받은 모든 응답이 적용된 상태이며, 이후 요청에는 영향을 받지 않는 사본입니다.
+    pub async fn document(&self) -> WebDynproDocument {
+        self.document.lock().await.clone()
+    }
+
+    /// 주어진 ID를 가진 컨트롤의 현재 HTML을 반환합니다.
+    pub async fn control_html(&self, id: &str) -> Option<String> {
+        self.document.lock().await.control_html(id)
+    }
+
+    /// 현재 화면에서 주어진 ID의 컨트롤을 찾습니다.
+    pub async fn find_element<T: Element>(&self, id: &str) -> Result<T, ApplicationError> {
+        let document = self.document.lock().await;
+        Ok(T::find(document.parsed(), id)?)
+    }
+
+    /// 주어진 ID의 테이블에서 모든 행을 읽습니다.
+    /// 화면에 보이지 않는 행은 세로 스크롤 이벤트를 보내 받아오며, 행 번호 순으로 중복 없이 반환합니다.
+    /// 스크롤해도 새 행을 받지 못해 전체 행을 읽을 수 없으면 오류를 반환합니다.
+    pub async fn read_table_rows(&self, id: &str) -> Result<Vec<SapTableRow>, ApplicationError> {
+        let table = self.find_element::<SapTable>(id).await?;
+        let Some(row_count) = table.row_count() else {
+            return Ok(table.rows().to_vec());
+        };
+
+        // 행 번호가 없는 행은 스크롤과 관계없이 처음 화면에서만 읽음
+        let mut unindexed_rows: Vec<SapTableRow> = table
+            .rows()
+            .iter()
+            .filter(|row| row.index().is_none())
+            .cloned()
+            .collect();
+        let mut rows = BTreeMap::new();
+        let mut table = table;
+        let mut scrolled = false;
+
+        loop {
+            let mut added = false;
+            for row in table.rows() {
+                if let Some(index) = row.index() {
+                    if let Entry::Vacant(entry) = rows.entry(index) {
+                        entry.insert(row.clone());
+                        added = true;
+                    }
+                }
+            }
+
+            let read = rows.len() + unindexed_rows.len();
+            if read >= row_count as usize {
+                break;
+            }
+            // 스크롤했는데도 새 행이 없으면 더 읽을 수 없음
+            if scrolled && !added {
+                return Err(ApplicationError::IncompleteTable {
+                    id: id.to_string(),
+                    expected: row_count,
+                    read,
+                });
+            }
+
+            let next_index = rows.keys().next_back().map_or(1, |index| index + 1);
+            self.send_event(table.vertical_scroll(next_index)).await?;
+            table = self.find_element::<SapTable>(id).await?;
+            scrolled = true;
+        }
+
+        let mut result: Vec<SapTableRow> = rows.into_values().collect();
+        result.append(&mut unindexed_rows);
+        Ok(result)
+    }
+
+    /// 주어진 행 번호의 행이 화면에 보이도록 테이블을 스크롤합니다.
+    pub async fn scroll_table_to(&self, id: &str, row_index: u32) -> Result<(), ApplicationError> {
+        let table = self.find_element::<SapTable>(id).await?;
+        if table
+            .rows()
+            .iter()
+            .any(|row| row.index() == Some(row_index))
+        {
+            return Ok(());
+        }
+
+        self.send_event(table.vertical_scroll(row_index)).await?;
+        Ok(())
+    }
+
+    /// 사용자 이벤트 하나를 브라우저와 같은 이벤트 큐로 감싸 전송하고 응답을 반환합니다.
+    pub async fn send_event(&self, event: SapEvent) -> Result<String, ApplicationError> {
+        self.send_events([event]).await
+    }
+
+    /// 사용자 이벤트들을 순서대로 하나의 이벤트 큐로 감싸 전송하고 응답을 반환합니다.
+    /// 앞에는 `ClientInspector_Notify`, 뒤에는 `Form_Request` 이벤트가 붙습니다.
+    pub async fn send_events(
+        &self,
+        events: impl IntoIterator<Item = SapEvent>,
+    ) -> Result<String, ApplicationError> {
+        let sap_event_queue = SapEventQueue::with_envelope(events).to_string();
+        self.send_request(Some(&sap_event_queue)).await
+    }
+
     // SAP 이벤트 큐 전송
+    // 받은 응답은 문서에 적용됩니다.
+    // 세션이 만료된 응답을 받으면 다시 인증하고 애플리케이션을 첫 화면부터 다시 엽니다.
+    // 이전 화면 상태(선택한 학년도 등)가 사라졌으므로 이벤트 큐는 다시 보내지 않고
+    // `SessionRecovered`를 반환하며, 호출하는 쪽에서 작업을 처음부터 다시 수행해야 합니다.
     pub async fn send_request(
         &self,
         sap_event_queue: Option<&str>,
-    ) -> Result<Response, ApplicationError> {
-        let url = format!(
-            "{}/{}",
-            SapSsrClient::SSU_WEBDYNPRO_BASE_URL,
-            self.sap_ssr_client.action_url
-        );
-
-        let use_beacon_str = self.sap_ssr_client.use_beacon.to_string();
-        let mut form_data = vec![
-            ("charset", self.sap_ssr_client.charset.as_str()),
-            (
-                "sap-wd-secure-id",
-                self.sap_ssr_client.wd_secure_id.as_str(),
-            ),
-            ("fesrAppName", self.sap_ssr_client.app_name.as_str()),
-            ("fesrUseBeacon", use_beacon_str.as_str()),
-        ];
+    ) -> Result<String, ApplicationError> {
+        let mut body = self.post_event_queue(sap_event_queue).await?;
+
+        if page::is_session_expired(&body) {
+            self.recover_session().await?;
+
+            if sap_event_queue.is_some() {
+                return Err(ApplicationError::SessionRecovered);
+            }
+
+            // 이벤트 없이 화면만 다시 받는 요청은 화면 상태와 관계없으므로 다시 보냄
+            body = self.post_event_queue(None).await?;
+
+            if page::is_session_expired(&body) {
+                return Err(ApplicationError::SessionExpired);
+            }
+        }
+
+        let response = WebDynproResponse::parse(&body)?;
+        self.document.lock().await.apply(&response)?;
+
+        Ok(body)
+    }
+
+    async fn post_event_queue(
+        &self,
+        sap_event_queue: Option<&str>,
+    ) -> Result<String, ApplicationError> {
+        let sap_ssr_client = self.sap_ssr_client.read().await;
+
+        let url = self.config.action_url(&sap_ssr_client.action_url);
+
+        let mut form_data = Self::form_data(&sap_ssr_client);
 
         if let Some(event_queue) = sap_event_queue {
-            form_data.push(("SAPEVENTQUEUE", event_queue));
+            form_data.push(("SAPEVENTQUEUE".to_string(), event_queue.to_string()));
         }
 
         let response = self.client.post(&url).form(&form_data).send().await?;
+        let body = response.text().await?;
+
+        Ok(body)
+    }
+
+    /// 서버에 열려 있는 WebDynpro 애플리케이션 인스턴스를 종료합니다.
+    ///
+    /// 브라우저는 창을 닫을 때 대기 중인 이벤트 큐를 `Form_Request`로 마무리해
+    /// `sap-sessioncmd=USR_ABORT`와 함께 한 번에 전송합니다. 같은 요청을 보내므로
+    /// 서버는 마지막 이벤트를 처리한 뒤 애플리케이션 인스턴스와 세션 컨텍스트를 정리합니다.
+    /// 종료 응답은 화면 변경분이 아니므로 문서에 적용하지 않고, 세션이 만료되었어도 다시 인증하지 않습니다.
+    pub async fn close(self) -> Result<(), ApplicationError> {
+        let sap_ssr_client = self.sap_ssr_client.read().await;
+
+        let url = self.config.action_url(&sap_ssr_client.action_url);
+
+        let mut form_data = Self::form_data(&sap_ssr_client);
+        form_data.push((
+            "SAPEVENTQUEUE".to_string(),
+            SapEventQueue::with_envelope([]).to_string(),
+        ));
+        form_data.push(("sap-sessioncmd".to_string(), "USR_ABORT".to_string()));
+
+        self.client
+            .post(&url)
+            .form(&form_data)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// 모든 요청에 공통으로 포함되는 SSR 폼 데이터
+    fn form_data(sap_ssr_client: &SapSsrClient) -> Vec<(String, String)> {
+        vec![
+            ("charset".to_string(), sap_ssr_client.charset.clone()),
+            (
+                "sap-wd-secure-id".to_string(),
+                sap_ssr_client.wd_secure_id.clone(),
+            ),
+            ("fesrAppName".to_string(), sap_ssr_client.app_name.clone()),
+            (
+                "fesrUseBeacon".to_string(),
+                sap_ssr_client.use_beacon.to_string(),
+            ),
+        ]
+    }
+
+    /// 세션을 다시 인증하고 SAP SSR Client를 다시 초기화합니다.
+    async fn recover_session(&self) -> Result<(), ApplicationError> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or(ApplicationError::SessionExpired)?;
+
+        // 토큰이나 저장된 세션으로 만든 세션은 다시 로그인할 수 없음
+        if !session.can_reauthenticate() {
+            return Err(ApplicationError::SessionNotRenewable);
+        }
+
+        session.reauthenticate().await?;
+
+        let sap_ssr_client =
+            SapSsrClient::new(self.client.clone(), &self.config, &self.app_name).await?;
+        *self.document.lock().await = WebDynproDocument::new(&sap_ssr_client.page);
+        *self.sap_ssr_client.write().await = sap_ssr_client;
+
+        Ok(())
+    }
+}
 
-        Ok(response)
+impl AsRef<Application> for Application {
+    fn as_ref(&self) -> &Application {
+        self
     }
 }
@@ -1,15 +1,19 @@
 pub mod model;
 
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{collections::HashMap, future::Future, ops::Deref, sync::Arc};
 
-use reqwest::{Client, Response};
-use scraper::{ElementRef, Html, Selector};
+use reqwest::Client;
 use thiserror::Error;
 
-use crate::webdynpro::{
-    client::SapSsrClientError,
-    event::{
-        SapEventBuilder, SapEventBuilderError, SapEventQueueBuilder, SapEventQueueBuilderError,
+use crate::{
+    config::USaintConfig,
+    session::USaintSession,
+    webdynpro::{
+        client::SapSsrClientError,
+        element::{
+            button::Button, combo_box::ComboBox, sap_table::SapTable, Element, ElementError,
+        },
+        event::{SapEventBuilderError, SapEventQueueBuilderError},
     },
 };
 
@@ -29,8 +33,16 @@ pub enum CourseGradesApplicationError {
     SapEventBuilderError(#[from] SapEventBuilderError),
     #[error("SAP 이벤트 큐 빌더 오류: {0}")]
     SapEventQueueBuilderError(#[from] SapEventQueueBuilderError),
+    #[error("컨트롤 오류: {0}")]
+    ElementError(#[from] ElementError),
     #[error("HTML 파싱 오류")]
     HtmlParseError,
+    #[error("{0}학년도는 선택할 수 있는 학년도가 아닙니다.")]
+    UnavailableYear(u32),
+    #[error("{0:?}는 선택할 수 있는 학기가 아닙니다.")]
+    UnavailableSemester(SemesterType),
+    #[error("{0} 콤보 박스의 항목 목록을 페이지에서 찾을 수 없습니다.")]
+    MissingComboBoxItems(String),
 }
 
 pub struct CourseGradesApplication(Application);
@@ -43,15 +55,18 @@ impl Deref for CourseGradesApplication {
     }
 }
 
+impl AsRef<Application> for CourseGradesApplication {
+    fn as_ref(&self) -> &Application {
+        &self.0
+    }
+}
+
 impl CourseGradesApplication {
     pub const APP_NAME: &'static str = "ZCMB3W0017";
 
-    const SEMESTER_GRADES_SUMMARY_TABLE_ID: &'static str =
-        "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE-contentTBody";
-    const SEMESTER_GRADES_DETAIL_TABLE_ID: &'static str =
-        "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE_1-contentTBody";
-    const COURSE_GRADES_DETAIL_TABLE_ID: &'static str =
-        "ZCMB3W0017.ID_0001:V_DETAIL.TABLE-contentTBody";
+    const SEMESTER_GRADES_SUMMARY_TABLE_ID: &'static str = "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE";
+    const SEMESTER_GRADES_DETAIL_TABLE_ID: &'static str = "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE_1";
+    const COURSE_GRADES_DETAIL_TABLE_ID: &'static str = "ZCMB3W0017.ID_0001:V_DETAIL.TABLE";
     const COURSE_GRADES_DETAIL_POPUP_CLOSE_BUTTON_ID: &'static str =
         "ZCMB3W0017.ID_0001:W_POPUP.WDBUTTON_5";
     const YEAR_COMBO_BOX_ID: &'static str =
@@ -66,38 +81,35 @@ impl CourseGradesApplication {
         Ok(CourseGradesApplication(application))
     }
 
+    /// 주어진 서버 설정으로 애플리케이션을 생성합니다.
+    pub async fn with_config(
+        client: Arc<Client>,
+        config: USaintConfig,
+    ) -> Result<CourseGradesApplication, CourseGradesApplicationError> {
+        let application = Application::with_config(client, config, Self::APP_NAME).await?;
+        Ok(CourseGradesApplication(application))
+    }
+
+    /// 세션이 만료되면 자동으로 다시 로그인하는 애플리케이션을 생성합니다.
+    pub async fn with_session(
+        session: Arc<USaintSession>,
+    ) -> Result<CourseGradesApplication, CourseGradesApplicationError> {
+        let application = Application::with_session(session, Self::APP_NAME).await?;
+        Ok(CourseGradesApplication(application))
+    }
+
+    /// 서버에 열려 있는 성적 조회 애플리케이션을 종료합니다.
+    pub async fn close(self) -> Result<(), CourseGradesApplicationError> {
+        self.0.close().await?;
+        Ok(())
+    }
+
     /// 모든 학기별 성적을 가져옵니다.
     pub async fn get_all_semester_grades(
         &self,
     ) -> Result<Vec<SemesterGrade>, CourseGradesApplicationError> {
-        let response = self.send_request(None).await?;
-        let body = response.text().await?;
-
-        // HTML 문자열 파싱
-        let document = Html::parse_document(&body);
-        // 학기별 성적 테이블 선택자
-        let tbody_selector = Selector::parse(
-            format!(r#"[id="{}"]"#, Self::SEMESTER_GRADES_SUMMARY_TABLE_ID).as_str(),
-        )
-        .unwrap();
-
-        let mut semester_grades = Vec::new();
-
-        for tbody_element in document.select(&tbody_selector) {
-            // tbody 요소의 한 단계 아래에 있는 tr 요소들을 순회
-            // 첫 번째 tr 요소는 테이블 헤더이므로 스킵
-            for child in tbody_element.children().skip(1) {
-                if let Some(element) = ElementRef::wrap(child) {
-                    // tr 요소이고 rr 속성(row index)이 0이 아닌 경우에만 성적 정보를 가져옴
-                    if element.value().name() == "tr" && element.attr("rr") != Some("0") {
-                        let semester_grade = SemesterGrade::from_html_element(element);
-                        semester_grades.push(semester_grade);
-                    }
-                }
-            }
-        }
-
-        Ok(semester_grades)
+        self.retry_after_recovery(|| self.read_all_semester_grades())
+            .await
     }
 
     /// 주어진 (년도, 학기)의 세부 성적을 가져옵니다.
@@ -107,153 +119,202 @@ impl CourseGradesApplication {
         year: u32,
         semester: SemesterType,
         fetch_details: bool,
+    ) -> Result<Vec<CourseGrade>, CourseGradesApplicationError> {
+        self.retry_after_recovery(|| {
+            self.read_semester_grades_details(year, semester, fetch_details)
+        })
+        .await
+    }
+
+    /// 작업 도중 세션이 만료되어 애플리케이션이 첫 화면으로 초기화되면, 작업을 처음부터 한 번 더 수행합니다.
+    async fn retry_after_recovery<T, F, Fut>(
+        &self,
+        operation: F,
+    ) -> Result<T, CourseGradesApplicationError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, CourseGradesApplicationError>>,
+    {
+        match operation().await {
+            Err(CourseGradesApplicationError::ApplicationError(
+                ApplicationError::SessionRecovered,
+            )) => operation().await,
+            result => result,
+        }
+    }
+
+    async fn read_all_semester_grades(
+        &self,
+    ) -> Result<Vec<SemesterGrade>, CourseGradesApplicationError> {
+        self.send_request(None).await?;
+
+        // 화면에 보이지 않는 학기까지 스크롤해 모두 읽음
+        let rows = self
+            .read_table_rows(Self::SEMESTER_GRADES_SUMMARY_TABLE_ID)
+            .await?;
+
+        let semester_grades = rows
+            .iter()
+            .map(SemesterGrade::from_row)
+            .collect::<Result<_, _>>()?;
+
+        Ok(semester_grades)
+    }
+
+    async fn read_semester_grades_details(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        fetch_details: bool,
     ) -> Result<Vec<CourseGrade>, CourseGradesApplicationError> {
         self.select_year(year).await?;
 
-        let response = self.select_semester(semester).await?;
-        let body = response.text().await?;
+        self.select_semester(semester).await?;
 
-        // HTML 문자열 파싱
-        let document = Html::parse_document(&body);
-        // 학기별 세부 성적 테이블 선택자
-        let tbody_selector = Selector::parse(
-            format!(r#"[id="{}"]"#, Self::SEMESTER_GRADES_DETAIL_TABLE_ID).as_str(),
-        )
-        .unwrap();
+        // 화면에 보이지 않는 과목까지 스크롤해 모두 읽음
+        let rows = self
+            .read_table_rows(Self::SEMESTER_GRADES_DETAIL_TABLE_ID)
+            .await?;
 
         let mut course_grades = Vec::new();
 
-        for tbody_element in document.select(&tbody_selector) {
-            // tbody 요소의 한 단계 아래에 있는 tr 요소들을 순회
-            // 첫 번째 tr 요소는 테이블 헤더이므로 스킵
-            for child in tbody_element.children().skip(1) {
-                if let Some(element) = ElementRef::wrap(child) {
-                    // tr 요소이고 rr 속성(row index)이 0이 아닌 경우에만 성적 정보를 가져옴
-                    if element.value().name() == "tr" && element.attr("rr") != Some("0") {
-                        let mut course_grade = CourseGrade::from_html_element(element);
-
-                        // `fetch_details` 값이 `true`이면 상세 성적을 함께 가져옴
-                        if fetch_details {
-                            let detailed_grades = self.get_course_grades_details(element).await?;
-                            course_grade.detailed_grade = detailed_grades;
-                        }
-
-                        course_grades.push(course_grade);
+        for row in &rows {
+            let mut course_grade = CourseGrade::from_row(row)?;
+
+            // `fetch_details` 값이 `true`이고 상세성적 조회 버튼이 있으면 상세 성적을 함께 가져옴
+            if fetch_details {
+                if let Some(button_id) = CourseGrade::detail_button_id(row) {
+                    // 스크롤로 화면에서 벗어난 행이면 버튼이 보이도록 다시 스크롤
+                    if let Some(row_index) = row.index() {
+                        self.scroll_table_to(Self::SEMESTER_GRADES_DETAIL_TABLE_ID, row_index)
+                            .await?;
                     }
+                    course_grade.detailed_grade = self.get_course_grades_details(button_id).await?;
                 }
             }
+
+            course_grades.push(course_grade);
         }
 
         Ok(course_grades)
     }
 
-    /// 주어진 과목의 상세 성적 정보(출석, 중간고사, 기말고사..)를 가져옵니다.
-    async fn get_course_grades_details<'tr>(
+    /// 주어진 상세성적 조회 버튼을 눌러 과목의 상세 성적 정보(출석, 중간고사, 기말고사..)를 가져옵니다.
+    async fn get_course_grades_details(
         &self,
-        tr_element: ElementRef<'tr>,
+        button_id: &str,
     ) -> Result<HashMap<String, f32>, CourseGradesApplicationError> {
-        // 5번째 자식 요소인 상세성적 조회 버튼의 id 값 가져오기
-        if let Some(td_element) = tr_element.children().nth(4) {
-            if let Some(td_element_ref) = ElementRef::wrap(td_element) {
-                let button_selector = Selector::parse(r#"[ct="B"]"#).unwrap();
-
-                match td_element_ref.select(&button_selector).next() {
-                    Some(button_element) => {
-                        let id_value = button_element.value().attr("id").unwrap();
-
-                        let sap_event_queue = SapEventQueueBuilder::default()
-                            .add_event(
-                                SapEventBuilder::default()
-                                    .event("ClientInspector")
-                                    .control("Notify")
-                                    .add_parameter(("Id".to_string(), "WD01".to_string()))
-                                    .add_parameter(("Data".to_string(), "".to_string()))
-                                    .build()?,
-                            )
-                            .add_event(
-                                SapEventBuilder::default()
-                                    .event("Button")
-                                    .control("Press")
-                                    .add_parameter(("Id".to_string(), id_value.to_string()))
-                                    .build()?,
-                            )
-                            .build()?
-                            .to_string();
-
-                        let response = self.send_request(Some(&sap_event_queue)).await?;
-                        let body = response.text().await?;
-
-                        // HTML 문자열 파싱
-                        let document = Html::parse_document(&body);
-                        // 과목 상세 성적 테이블 선택자
-                        let tbody_selector = Selector::parse(
-                            format!(r#"[id="{}"]"#, Self::COURSE_GRADES_DETAIL_TABLE_ID).as_str(),
-                        )
-                        .unwrap();
-
-                        let tbody_element = document.select(&tbody_selector).next().unwrap();
-                        let detailed_grades = CourseGrade::create_detailed_grades(tbody_element);
-
-                        // 상세 성적 조회 팝업 창 닫기
-                        self.close_popup_window().await?;
-
-                        return Ok(detailed_grades);
-                    }
-                    None => return Ok(HashMap::new()),
-                }
-            }
-        }
+        let button = self.find_element::<Button>(button_id).await?;
+
+        self.send_event(button.press()).await?;
+
+        let table = self
+            .find_element::<SapTable>(Self::COURSE_GRADES_DETAIL_TABLE_ID)
+            .await?;
+        let detailed_grades = CourseGrade::create_detailed_grades(&table)?;
 
-        Err(CourseGradesApplicationError::HtmlParseError)
+        // 상세 성적 조회 팝업 창 닫기
+        self.close_popup_window().await?;
+
+        Ok(detailed_grades)
     }
 
     /// 상세 성적 조회 팝업 창을 닫습니다.
-    async fn close_popup_window(&self) -> Result<Response, CourseGradesApplicationError> {
-        let sap_event_queue = SapEventBuilder::default()
-            .event("Button")
-            .control("Press")
-            .add_parameter((
-                "Id".to_string(),
-                Self::COURSE_GRADES_DETAIL_POPUP_CLOSE_BUTTON_ID.to_string(),
-            ))
-            .build()?
-            .to_string();
-
-        let response = self.send_request(Some(&sap_event_queue)).await?;
-
-        Ok(response)
+    async fn close_popup_window(&self) -> Result<String, CourseGradesApplicationError> {
+        let close_button = self
+            .find_element::<Button>(Self::COURSE_GRADES_DETAIL_POPUP_CLOSE_BUTTON_ID)
+            .await?;
+
+        let body = self.send_event(close_button.press()).await?;
+
+        Ok(body)
+    }
+
+    /// 학년도 콤보 박스에서 선택할 수 있는 학년도 목록을 가져옵니다.
+    pub async fn available_years(&self) -> Result<Vec<u32>, CourseGradesApplicationError> {
+        let year_combo_box = self
+            .find_element::<ComboBox>(Self::YEAR_COMBO_BOX_ID)
+            .await?;
+
+        let years = year_combo_box
+            .items()
+            .iter()
+            .filter_map(|item| item.key.parse().ok())
+            .collect();
+
+        Ok(years)
+    }
+
+    /// 학기 콤보 박스에서 선택할 수 있는 학기 목록을 가져옵니다.
+    pub async fn available_semesters(
+        &self,
+    ) -> Result<Vec<SemesterType>, CourseGradesApplicationError> {
+        let semester_combo_box = self
+            .find_element::<ComboBox>(Self::SEMESTER_COMBO_BOX_ID)
+            .await?;
+
+        let semesters = semester_combo_box
+            .items()
+            .iter()
+            .filter_map(|item| SemesterType::from_key(&item.key))
+            .collect();
+
+        Ok(semesters)
     }
 
     /// 주어진 년도를 선택하는 SAP 이벤트를 발행하고 응답을 반환합니다.
-    async fn select_year(&self, year: u32) -> Result<Response, CourseGradesApplicationError> {
-        let sap_event_queue = SapEventBuilder::default()
-            .event("ComboBox")
-            .control("Select")
-            .add_parameter(("Id".to_string(), Self::YEAR_COMBO_BOX_ID.to_string()))
-            .add_parameter(("Key".to_string(), year.to_string()))
-            .build()?
-            .to_string();
-
-        let response = self.send_request(Some(&sap_event_queue)).await?;
-
-        Ok(response)
+    /// 목록에 없는 년도는 이벤트를 보내기 전에 거부합니다.
+    async fn select_year(&self, year: u32) -> Result<String, CourseGradesApplicationError> {
+        let year_combo_box = self
+            .find_element::<ComboBox>(Self::YEAR_COMBO_BOX_ID)
+            .await?;
+
+        ensure_selectable(&year_combo_box, &year.to_string(), || {
+            CourseGradesApplicationError::UnavailableYear(year)
+        })?;
+
+        let body = self.send_event(year_combo_box.select(year)).await?;
+
+        Ok(body)
     }
 
     /// 주어진 학기를 선택하는 SAP 이벤트를 발행하고 응답을 반환합니다.
+    /// 목록에 없는 학기는 이벤트를 보내기 전에 거부합니다.
     async fn select_semester(
         &self,
         semester: SemesterType,
-    ) -> Result<Response, CourseGradesApplicationError> {
-        let sap_event_queue = SapEventBuilder::default()
-            .event("ComboBox")
-            .control("Select")
-            .add_parameter(("Id".to_string(), Self::SEMESTER_COMBO_BOX_ID.to_string()))
-            .add_parameter(("Key".to_string(), semester.key().to_string()))
-            .build()?
-            .to_string();
-
-        let response = self.send_request(Some(&sap_event_queue)).await?;
-
-        Ok(response)
+    ) -> Result<String, CourseGradesApplicationError> {
+        let semester_combo_box = self
+            .find_element::<ComboBox>(Self::SEMESTER_COMBO_BOX_ID)
+            .await?;
+
+        ensure_selectable(&semester_combo_box, semester.key(), || {
+            CourseGradesApplicationError::UnavailableSemester(semester)
+        })?;
+
+        let body = self
+            .send_event(semester_combo_box.select(semester.key()))
+            .await?;
+
+        Ok(body)
+    }
+}
+
+/// 콤보 박스에서 주어진 키를 선택할 수 있는지 확인합니다.
+/// 항목 목록(ListBox)을 참조하는데 페이지에서 항목을 찾지 못했으면, 검증 없이 보내지 않고 오류를 반환합니다.
+fn ensure_selectable(
+    combo_box: &ComboBox,
+    key: &str,
+    unavailable: impl FnOnce() -> CourseGradesApplicationError,
+) -> Result<(), CourseGradesApplicationError> {
+    if combo_box.item_list_box_id().is_some() && combo_box.items().is_empty() {
+        return Err(CourseGradesApplicationError::MissingComboBoxItems(
+            combo_box.id().to_string(),
+        ));
     }
+    if !combo_box.items().is_empty() && combo_box.item(key).is_none() {
+        return Err(unavailable());
+    }
+
+    Ok(())
 }
@@ -1,5 +1,10 @@
 use std::collections::HashMap;
 
+use crate::webdynpro::element::{
+    sap_table::{SapTable, SapTableCell, SapTableCellKind, SapTableRow},
+    ElementError,
+};
+
 // 학기별 성적
 #[derive(Debug)]
 pub struct SemesterGrade {
@@ -19,71 +24,35 @@ pub struct SemesterGrade {
 }
 
 impl SemesterGrade {
-    pub fn from_html_element(tr_element: scraper::ElementRef) -> SemesterGrade {
-        let td_selector = scraper::Selector::parse("td").unwrap();
-        // 첫 번째 td 요소는 라디오 버튼이므로 skip(1)을 사용하여 제외
-        let td_elements: Vec<_> = tr_element.select(&td_selector).skip(1).collect();
-
-        let year_text = td_elements[0].text().collect::<String>();
-        let year = year_text.trim().parse().unwrap();
-
-        let semester = td_elements[1].text().collect::<String>().trim().to_string();
-
-        let attempted_credits_text = td_elements[2].text().collect::<String>();
-        let attempted_credits = attempted_credits_text.trim().parse().unwrap();
-
-        let earned_credits_text = td_elements[3].text().collect::<String>();
-        let earned_credits = earned_credits_text.trim().parse().unwrap();
-
-        let pf_earned_credits_text = td_elements[4].text().collect::<String>();
-        let pf_earned_credits = pf_earned_credits_text.trim().parse().unwrap();
-
-        let grade_points_average_text = td_elements[5].text().collect::<String>();
-        let grade_points_average = grade_points_average_text.trim().parse().unwrap();
-
-        let grade_points_sum_text = td_elements[6].text().collect::<String>();
-        let grade_points_sum = grade_points_sum_text.trim().parse().unwrap();
-
-        let arithmetic_mean_text = td_elements[7].text().collect::<String>();
-        let arithmetic_mean = arithmetic_mean_text.trim().parse().unwrap();
-
-        let semester_rank_text = td_elements[8].text().collect::<String>();
-        let semester_rank_parts: Vec<&str> = semester_rank_text.trim().split('/').collect();
-        let semester_rank = (
-            semester_rank_parts[0].parse().unwrap(),
-            semester_rank_parts[1].parse().unwrap(),
-        );
-
-        let general_rank_text = td_elements[9].text().collect::<String>();
-        let general_rank_parts: Vec<&str> = general_rank_text.trim().split('/').collect();
-        let general_rank = (
-            general_rank_parts[0].parse().unwrap(),
-            general_rank_parts[1].parse().unwrap(),
-        );
-
-        let academic_probation = td_elements[10].text().collect::<String>().trim() == "Y";
-        let consult = td_elements[11].text().collect::<String>().trim() == "Y";
-        let flunked = td_elements[12].text().collect::<String>().trim() == "Y";
-
-        SemesterGrade {
-            year,
-            semester,
-            attempted_credits,
-            earned_credits,
-            pf_earned_credits,
-            grade_points_average,
-            grade_points_sum,
-            arithmetic_mean,
-            semester_rank,
-            general_rank,
-            academic_probation,
-            consult,
-            flunked,
-        }
+    /// 학기별 성적 테이블의 행 하나를 열 이름으로 읽습니다.
+    pub fn from_row(row: &SapTableRow) -> Result<SemesterGrade, ElementError> {
+        Ok(SemesterGrade {
+            year: row.parse("학년도")?,
+            semester: row.text("학기")?.to_string(),
+            attempted_credits: row.parse("신청학점")?,
+            earned_credits: row.parse("취득학점")?,
+            pf_earned_credits: row.parse("P/F학점")?,
+            grade_points_average: row.parse("평점평균")?,
+            grade_points_sum: row.parse("평점계")?,
+            arithmetic_mean: row.parse("산술평균")?,
+            semester_rank: parse_rank(row, "학기별석차")?,
+            general_rank: parse_rank(row, "전체석차")?,
+            academic_probation: row.text("학사경고")? == "Y",
+            consult: row.text("상담여부")? == "Y",
+            flunked: row.text("유급")? == "Y",
+        })
     }
 }
 
-#[derive(Debug)]
+/// `석차/전체 인원` 형식의 석차를 읽습니다.
+fn parse_rank(row: &SapTableRow, header: &str) -> Result<(u32, u32), ElementError> {
+    let text = row.text(header)?;
+    text.split_once('/')
+        .and_then(|(rank, total)| Some((rank.trim().parse().ok()?, total.trim().parse().ok()?)))
+        .ok_or_else(|| row.invalid_cell(header, text))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SemesterType {
     FirstSemester,
     SummerSemester,
@@ -101,6 +70,17 @@ impl SemesterType {
             SemesterType::WinterSemester => "093",
         }
     }
+
+    /// sap 내부 key에 대응하는 학기를 반환합니다.
+    pub fn from_key(key: &str) -> Option<SemesterType> {
+        match key {
+            "090" => Some(SemesterType::FirstSemester),
+            "091" => Some(SemesterType::SummerSemester),
+            "092" => Some(SemesterType::SecondSemester),
+            "093" => Some(SemesterType::WinterSemester),
+            _ => None,
+        }
+    }
 }
 
 // 과목별 성적
@@ -117,61 +97,62 @@ pub struct CourseGrade {
 }
 
 impl CourseGrade {
-    pub fn from_html_element(tr_element: scraper::ElementRef) -> CourseGrade {
-        let td_selector = scraper::Selector::parse("td").unwrap();
-        // 첫 번째 td 요소는 라디오 버튼이므로 skip(1)을 사용하여 제외
-        let td_elements: Vec<_> = tr_element.select(&td_selector).skip(1).collect();
-
-        let grade = td_elements[0].text().collect::<String>().trim().to_string();
-        let rating = td_elements[1].text().collect::<String>().trim().to_string();
-        let course_name = td_elements[2].text().collect::<String>().trim().to_string();
-
-        let detailed_grade = HashMap::new();
-
-        let course_credits_text = td_elements[4].text().collect::<String>();
-        let course_credits = course_credits_text.trim().parse().unwrap();
-
-        let professor_name = td_elements[5].text().collect::<String>().trim().to_string();
-        let remarks = td_elements[6].text().collect::<String>().trim().to_string();
-        let course_code = td_elements[7].text().collect::<String>().trim().to_string();
-
-        CourseGrade {
-            grade,
-            rating,
-            course_name,
-            detailed_grade,
-            course_credits,
-            professor_name,
-            remarks,
-            course_code,
-        }
+    /// 학기별 세부 성적 테이블의 행 하나를 열 이름으로 읽습니다.
+    /// 상세 성적은 비워 두며, 필요하면 상세 성적 조회 버튼을 눌러 따로 채웁니다.
+    pub fn from_row(row: &SapTableRow) -> Result<CourseGrade, ElementError> {
+        Ok(CourseGrade {
+            grade: row.text("성적")?.to_string(),
+            rating: row.text("등급")?.to_string(),
+            course_name: row.text("과목명")?.to_string(),
+            detailed_grade: HashMap::new(),
+            course_credits: row.parse("과목학점")?,
+            professor_name: row.text("교수명")?.to_string(),
+            remarks: row.text("비고")?.to_string(),
+            course_code: row.text("과목코드")?.to_string(),
+        })
     }
 
-    pub fn create_detailed_grades(tbody_element: scraper::ElementRef) -> HashMap<String, f32> {
-        let mut detailed_grades = HashMap::new();
-
-        let key_table_row = tbody_element.children().nth(0).unwrap();
-        let value_table_row = tbody_element.children().nth(1).unwrap();
-
-        let key_elements: Vec<_> = key_table_row.children().skip(4).collect();
-        let value_elements: Vec<_> = value_table_row.children().skip(4).collect();
-
-        for (key_element, value_element) in key_elements.iter().zip(value_elements.iter()) {
-            let key_element_ref = scraper::ElementRef::wrap(*key_element).unwrap();
-            let value_element_ref = scraper::ElementRef::wrap(*value_element).unwrap();
-
-            let key = key_element_ref
-                .text()
-                .collect::<String>()
-                .trim()
-                .to_string();
-
-            let value_text = value_element_ref.text().collect::<String>();
-            let value = value_text.trim().parse().unwrap();
-
-            detailed_grades.insert(key, value);
-        }
+    /// 상세 성적 조회 버튼의 ID
+    pub fn detail_button_id(row: &SapTableRow) -> Option<&str> {
+        row.get("상세성적")
+            .filter(|cell| *cell.kind() == SapTableCellKind::Button)
+            .and_then(SapTableCell::control_id)
+    }
 
-        detailed_grades
+    /// 과목 상세 성적 테이블에서 (항목, 점수)를 읽습니다.
+    /// 테이블의 첫 행에 항목별 점수가 있으며, 과목 정보 열과 이름 없는 열은 점수가 아니므로 제외합니다.
+    /// 아직 입력되지 않은 빈 점수는 건너뛰고, 숫자가 아닌 점수는 `InvalidCell` 오류를 반환합니다.
+    pub fn create_detailed_grades(table: &SapTable) -> Result<HashMap<String, f32>, ElementError> {
+        let Some(row) = table.rows().first() else {
+            return Ok(HashMap::new());
+        };
+
+        row.cells()
+            .filter(|(header, _)| {
+                !header.is_empty() && !DETAILED_GRADES_INFO_HEADERS.contains(header)
+            })
+            .filter(|(_, cell)| !cell.text().is_empty())
+            .map(|(header, cell)| {
+                let score = cell
+                    .text()
+                    .parse()
+                    .map_err(|_| row.invalid_cell(header, cell.text()))?;
+                Ok((header.to_string(), score))
+            })
+            .collect()
     }
 }
+
+/// 과목 상세 성적 테이블에서 점수가 아닌 과목 정보 열
+const DETAILED_GRADES_INFO_HEADERS: [&str; 10] = [
+    "학년도",
+    "학기",
+    "과목코드",
+    "과목명",
+    "분반",
+    "교수명",
+    "과목학점",
+    "성적",
+    "등급",
+    "비고",
+];
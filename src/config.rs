@@ -0,0 +1,58 @@
+use derive_builder::Builder;
+
+/// 유세인트 로그인 폼 URL
+pub const SAP_LOGIN_FORM_REQUEST_URL: &str =
+    "https://hana-prd-ap-4.ssu.ac.kr:8443/sap/bc/webdynpro/sap";
+/// 유세인트 WebDynpro 애플리케이션이 위치한 서버 URL
+pub const SSU_WEBDYNPRO_BASE_URL: &str = "https://ecc.ssu.ac.kr";
+/// WebDynpro 애플리케이션 경로의 접두사
+pub const SSU_WEBDYNPRO_PATH_PREFIX: &str = "/sap/bc/webdynpro/SAP";
+/// 스마트 ID 통합 로그인 처리 URL
+pub const SMARTID_LOGIN_URL: &str = "https://smartid.ssu.ac.kr/Symtra_sso/smln_pcs.asp";
+/// 스마트 ID 토큰을 SAP 로그온 티켓으로 교환하는 유세인트 포털 URL
+pub const SSU_USAINT_SSO_URL: &str = "https://saint.ssu.ac.kr/webSSO/sso.jsp";
+
+/// 클라이언트가 접속할 서버 주소 설정
+/// 기본값은 숭실대학교 운영 서버이며, 로컬 목(mock) 서버나 스테이징 서버를 대상으로 바꿀 수 있습니다.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(into), default)]
+pub struct USaintConfig {
+    pub login_url: String,
+    pub webdynpro_base_url: String,
+    pub webdynpro_path_prefix: String,
+    pub smartid_login_url: String,
+    pub portal_sso_url: String,
+}
+
+impl Default for USaintConfig {
+    fn default() -> Self {
+        USaintConfig {
+            login_url: SAP_LOGIN_FORM_REQUEST_URL.to_string(),
+            webdynpro_base_url: SSU_WEBDYNPRO_BASE_URL.to_string(),
+            webdynpro_path_prefix: SSU_WEBDYNPRO_PATH_PREFIX.to_string(),
+            smartid_login_url: SMARTID_LOGIN_URL.to_string(),
+            portal_sso_url: SSU_USAINT_SSO_URL.to_string(),
+        }
+    }
+}
+
+impl USaintConfig {
+    /// 주어진 WebDynpro 애플리케이션을 처음 불러오는 URL을 반환합니다.
+    pub fn application_url(&self, app_name: &str) -> String {
+        format!(
+            "{}{}/{}?sap-wd-stableids=x",
+            self.webdynpro_base_url.trim_end_matches('/'),
+            self.webdynpro_path_prefix.trim_end_matches('/'),
+            app_name
+        )
+    }
+
+    /// SSR 폼의 action 경로를 WebDynpro 서버 기준의 전체 URL로 변환합니다.
+    pub fn action_url(&self, action: &str) -> String {
+        format!(
+            "{}/{}",
+            self.webdynpro_base_url.trim_end_matches('/'),
+            action.trim_start_matches('/')
+        )
+    }
+}
@@ -1,3 +1,4 @@
 pub mod applications;
+pub mod config;
 pub mod session;
 pub mod webdynpro;
@@ -1,7 +1,6 @@
 use mini_rusaint::{
     applications::course_grades::{model::SemesterType, CourseGradesApplication},
     session::USaintSession,
-    webdynpro::event,
 };
 
 #[tokio::main]
@@ -1,112 +1,553 @@
-use std::{env, sync::Arc};
-
-use dotenv::dotenv;
-use reqwest::{
-    cookie::{CookieStore, Jar},
-    header::{HeaderMap, HeaderValue, USER_AGENT},
-    Client, Error as ReqwestError, Response, Result as ReqwestResult, Url,
-};
+use std::{path::Path, sync::Arc, time::Duration};
+
+use reqwest::{cookie::CookieStore, Client, Error as ReqwestError, Url};
 use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use zeroize::Zeroizing;
+
+use crate::{config::USaintConfig, webdynpro::page::is_login_page};
+
+pub mod builder;
+pub mod cookies;
+pub mod credentials;
+pub mod keepalive;
+pub mod lockout;
+pub mod login;
+pub mod manager;
+pub mod password;
+mod smartid;
+pub mod store;
+pub mod ticket;
+
+use builder::USaintSessionBuilder;
+use cookies::SessionCookieStore;
+use credentials::{CredentialError, CredentialProvider, Credentials};
+use keepalive::KeepAlive;
+use lockout::LoginGuard;
+use password::PasswordPolicyError;
+use store::SessionSnapshot;
+use ticket::{LogonTicket, LogonTicketError};
 
-const SAP_LOGIN_FORM_REQUEST_URL: &str =
-    "https://hana-prd-ap-4.ssu.ac.kr:8443/sap/bc/webdynpro/sap";
 const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Safari/537.36";
+const SSO_COOKIE_NAME: &str = "MYSAPSSO2";
+const SAP_LOGOFF_PATH: &str = "/sap/public/bc/icf/logoff";
 
 #[derive(Debug, Error)]
 pub enum USaintSessionError {
-    #[error("횐경 변수 오류: {0}")]
-    EnvVarError(#[from] env::VarError),
+    #[error("계정 정보 오류: {0}")]
+    CredentialError(#[from] CredentialError),
     #[error("HTTP 요청 오류: {0}")]
     RequestError(#[from] ReqwestError),
     #[error("MYSAPSSO2 쿠키가 존재하지 않습니다.")]
     MissingMYSAPSSO2Cookie,
+    #[error("파일 입출력 오류: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("세션 직렬화 오류: {0}")]
+    SerializationError(#[from] serde_json::Error),
+    #[error("저장된 세션이 만료되었거나 더 이상 유효하지 않습니다.")]
+    InvalidStoredSession,
+    #[error("세션을 다시 인증할 계정 정보가 없습니다.")]
+    MissingCredentials,
+    #[error("비밀번호가 올바르지 않습니다: {0}")]
+    WrongPassword(String),
+    #[error("존재하지 않는 사용자입니다: {0}")]
+    UnknownUser(String),
+    #[error("계정이 잠겼습니다: {0}")]
+    AccountLocked(String),
+    #[error("비밀번호가 만료되어 변경이 필요합니다: {0}")]
+    PasswordExpired(String),
+    #[error("서버 점검 중입니다: {0}")]
+    ServerMaintenance(String),
+    #[error("로그인에 실패했습니다: {0}")]
+    LoginFailed(String),
+    #[error("로그온 티켓 해석 오류: {0}")]
+    TicketError(#[from] LogonTicketError),
+    #[error("주어진 MYSAPSSO2 토큰이 만료되었거나 유효하지 않습니다.")]
+    InvalidToken,
+    #[error("올바르지 않은 User-Agent 값입니다: {0}")]
+    InvalidUserAgent(String),
+    #[error("스마트 ID 로그인 토큰(sToken)이 발급되지 않았습니다.")]
+    MissingSmartIdToken,
+    #[error("{id} 계정의 로그인이 연속 {failures}번 실패해 더 이상 시도하지 않습니다. 실패 횟수를 초기화한 뒤 다시 시도하세요.")]
+    TooManyFailedLogins { id: String, failures: u32 },
+    #[error("비밀번호 정책 위반: {0}")]
+    PasswordPolicy(#[from] PasswordPolicyError),
+    #[error("{0:?} 로그인 방식에서는 만료된 비밀번호를 변경할 수 없습니다.")]
+    PasswordChangeUnsupported(LoginStrategy),
+    #[error("비밀번호가 만료되지 않아 변경 화면이 표시되지 않았습니다.")]
+    PasswordNotExpired,
 }
 
-#[derive(Debug)]
-struct Credentials {
-    id: String,
-    password: String,
-}
-
-impl Credentials {
-    fn new(id: String, password: String) -> Self {
-        Credentials { id, password }
+impl USaintSessionError {
+    /// 잘못된 계정 정보로 인한 로그인 실패인지 확인합니다.
+    /// 네트워크 오류나 서버 점검처럼 계정과 관계없는 실패는 포함하지 않습니다.
+    pub fn is_authentication_failure(&self) -> bool {
+        matches!(
+            self,
+            USaintSessionError::WrongPassword(_)
+                | USaintSessionError::UnknownUser(_)
+                | USaintSessionError::AccountLocked(_)
+                | USaintSessionError::LoginFailed(_)
+        )
     }
+}
 
-    fn from_env() -> Result<Credentials, USaintSessionError> {
-        dotenv().ok();
-        Ok(Credentials {
-            id: env::var("USAINT_ID")?,
-            password: env::var("USAINT_PASSWORD")?,
-        })
-    }
+/// SAP 로그온 티켓(MYSAPSSO2)을 발급받는 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoginStrategy {
+    /// WebDynpro 로그인 폼에 아이디와 비밀번호를 직접 전송합니다.
+    #[default]
+    SapLogonForm,
+    /// 스마트 ID 통합 로그인으로 토큰을 발급받아 유세인트 포털에서 티켓으로 교환합니다.
+    SmartId,
 }
 
 pub struct USaintSession {
     pub client: Arc<Client>,
+    cookie_store: Arc<SessionCookieStore>,
+    credentials: Option<Credentials>,
+    login_strategy: LoginStrategy,
+    login_guard: Arc<LoginGuard>,
+    config: USaintConfig,
 }
 
 impl USaintSession {
+    /// 타임아웃, 프록시 등 HTTP 클라이언트 옵션을 지정할 수 있는 빌더를 반환합니다.
+    pub fn builder() -> USaintSessionBuilder {
+        USaintSessionBuilder::new()
+    }
+
     /// 주어진 유세인트 아이디와 비밀번호로 세션을 생성합니다.
     pub async fn with_password(id: String, password: String) -> Result<Self, USaintSessionError> {
-        let credentials = Credentials::new(id, password);
-        Self::create_session(credentials).await
+        Self::builder().with_password(id, password).await
+    }
+
+    /// 비밀번호가 만료되어 로그인이 `PasswordExpired`로 실패했을 때, 새 비밀번호로 변경하면서 세션을 생성합니다.
+    /// 생성된 세션은 이후 재인증에 새 비밀번호를 사용합니다.
+    pub async fn with_password_change(
+        id: String,
+        old_password: String,
+        new_password: String,
+    ) -> Result<Self, USaintSessionError> {
+        Self::builder()
+            .with_password_change(id, old_password, new_password)
+            .await
     }
 
     /// 환경 변수에서 유세인트 아이디와 비밀번호를 읽어 세션을 생성합니다
     pub async fn with_env() -> Result<Self, USaintSessionError> {
-        let credentials = Credentials::from_env()?;
-        Self::create_session(credentials).await
+        Self::builder().with_env().await
+    }
+
+    /// 주어진 `CredentialProvider`에서 계정 정보를 가져와 세션을 생성합니다.
+    pub async fn with_provider(
+        provider: &impl CredentialProvider,
+    ) -> Result<Self, USaintSessionError> {
+        Self::with_config(provider, USaintConfig::default()).await
     }
 
-    async fn create_session(credentials: Credentials) -> Result<Self, USaintSessionError> {
-        println!("USAINT_ID: {}", credentials.id);
-        println!("USAINT_PASSWORD: {}", credentials.password);
+    /// 주어진 서버 설정과 `CredentialProvider`로 세션을 생성합니다.
+    pub async fn with_config(
+        provider: &impl CredentialProvider,
+        config: USaintConfig,
+    ) -> Result<Self, USaintSessionError> {
+        Self::builder().config(config).with_provider(provider).await
+    }
 
-        // 기본 헤더 설정
-        let mut headers = HeaderMap::new();
+    /// 이미 발급받은 MYSAPSSO2 토큰(예: 브라우저나 다른 서비스에서 로그인한 토큰)으로 세션을 생성합니다.
+    /// 토큰이 유효하지 않으면 `InvalidToken` 오류를 반환합니다.
+    /// 계정 정보가 없는 세션이므로 토큰이 만료되면 다시 인증할 수 없습니다.
+    pub async fn with_token(token: &str) -> Result<Self, USaintSessionError> {
+        Self::builder().with_token(token).await
+    }
 
-        headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_USER_AGENT));
+    /// 저장된 세션 스냅샷으로부터 세션을 복원합니다.
+    /// 복원한 세션이 더 이상 유효하지 않으면 `InvalidStoredSession` 오류를 반환합니다.
+    ///
+    /// 스냅샷에는 계정 정보가 없으므로, 복원한 세션이 만료되면 `reauthenticate`는
+    /// `MissingCredentials`를 반환하고 애플리케이션도 세션을 복구하지 못합니다.
+    pub async fn from_snapshot(snapshot: &SessionSnapshot) -> Result<Self, USaintSessionError> {
+        Self::from_snapshot_with_config(snapshot, USaintConfig::default()).await
+    }
 
-        let cookie_store = Arc::new(Jar::default());
-        let client = Client::builder()
-            .default_headers(headers)
-            .cookie_provider(cookie_store.clone())
-            .build()?;
+    /// 주어진 서버 설정으로 세션 스냅샷을 복원합니다.
+    pub async fn from_snapshot_with_config(
+        snapshot: &SessionSnapshot,
+        config: USaintConfig,
+    ) -> Result<Self, USaintSessionError> {
+        Self::builder().config(config).from_snapshot(snapshot).await
+    }
 
-        // SAP SSO 토큰 발급
-        Self::fetch_sso_token(&client, &credentials).await?;
+    async fn restore_session(
+        client: Client,
+        cookie_store: Arc<SessionCookieStore>,
+        snapshot: &SessionSnapshot,
+        login_strategy: LoginStrategy,
+        login_guard: Arc<LoginGuard>,
+        config: USaintConfig,
+    ) -> Result<Self, USaintSessionError> {
+        if !snapshot.contains(SSO_COOKIE_NAME) {
+            return Err(USaintSessionError::InvalidStoredSession);
+        }
 
-        // 쿠키 저장소에 "MYSAPSSO2" 쿠키가 있는지 확인
-        let parsed_url = Url::parse(SAP_LOGIN_FORM_REQUEST_URL).unwrap();
+        for cookie in &snapshot.cookies {
+            let Ok(url) = Url::parse(&cookie.url) else {
+                return Err(USaintSessionError::InvalidStoredSession);
+            };
+            cookie_store.add_cookie_str(&cookie.to_set_cookie(), &url);
+        }
+
+        let session = USaintSession {
+            client: Arc::new(client),
+            cookie_store,
+            credentials: None,
+            login_strategy,
+            login_guard,
+            config,
+        };
+
+        // 복원한 세션이 실제로 사용 가능한지 확인
+        if !session.is_valid().await? {
+            return Err(USaintSessionError::InvalidStoredSession);
+        }
+
+        Ok(session)
+    }
+
+    /// 파일에 저장된 세션을 복원합니다.
+    /// `from_snapshot`과 마찬가지로 복원한 세션은 만료되면 다시 인증할 수 없습니다.
+    pub async fn from_file(path: impl AsRef<Path>) -> Result<Self, USaintSessionError> {
+        let contents = tokio::fs::read_to_string(path).await?;
+        let snapshot: SessionSnapshot = serde_json::from_str(&contents)?;
+        Self::from_snapshot(&snapshot).await
+    }
+
+    /// 현재 세션의 쿠키(MYSAPSSO2 등)를 `Domain`, `Path`, `Secure` 속성과 함께 스냅샷으로 만듭니다.
+    pub fn snapshot(&self) -> SessionSnapshot {
+        let mut snapshot = SessionSnapshot::default();
+
+        for url in [&self.config.login_url, &self.config.webdynpro_base_url] {
+            let Ok(parsed_url) = Url::parse(url) else {
+                continue;
+            };
+            for mut cookie in self.cookie_store.stored_cookies(&parsed_url) {
+                cookie.url = url.to_string();
+                snapshot.add_stored_cookie(cookie);
+            }
+        }
+
+        snapshot
+    }
+
+    /// 현재 세션을 파일에 저장합니다. 저장된 파일은 `from_file`로 복원할 수 있습니다.
+    ///
+    /// 파일에는 MYSAPSSO2 로그온 티켓이 평문으로 들어 있어, 파일을 읽을 수 있으면 누구나 이 계정으로
+    /// 로그인할 수 있습니다. Unix에서는 소유자만 읽고 쓸 수 있도록(`0600`) 파일을 만들며,
+    /// 이미 있는 파일을 덮어쓸 때도 권한을 `0600`으로 맞춥니다.
+    pub async fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), USaintSessionError> {
+        let contents = serde_json::to_string_pretty(&self.snapshot())?;
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(path).await?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))
+                .await?;
+        }
+        file.write_all(contents.as_bytes()).await?;
+        file.flush().await?;
+
+        Ok(())
+    }
+
+    /// 세션이 아직 유효한지 확인합니다.
+    /// 로그인 페이지로 되돌아가면 SSO 토큰이 만료된 것으로 판단합니다.
+    pub async fn is_valid(&self) -> Result<bool, USaintSessionError> {
+        if !self.has_sso_cookie() {
+            return Ok(false);
+        }
+
+        let response = self.client.get(&self.config.login_url).send().await?;
+        let body = response.text().await?;
+
+        Ok(!is_login_page(&body))
+    }
+
+    /// 세션이 만료되었을 때 저장된 계정 정보로 다시 로그인합니다.
+    /// 같은 `Client`와 쿠키 저장소를 재사용하므로 기존에 공유된 `client`도 새 SSO 토큰을 사용하게 됩니다.
+    pub async fn reauthenticate(&self) -> Result<(), USaintSessionError> {
+        let credentials = self
+            .credentials
+            .as_ref()
+            .ok_or(USaintSessionError::MissingCredentials)?;
+
+        Self::fetch_sso_token(
+            &self.client,
+            &self.cookie_store,
+            &self.config,
+            self.login_strategy,
+            &self.login_guard,
+            credentials,
+        )
+        .await?;
+
+        if !self.has_sso_cookie() {
+            return Err(USaintSessionError::MissingMYSAPSSO2Cookie);
+        }
+
+        Ok(())
+    }
 
-        if let Some(cookie) = cookie_store.cookies(&parsed_url) {
-            if cookie.to_str().unwrap().contains("MYSAPSSO2") {
-                return Ok(USaintSession {
-                    client: Arc::new(client),
-                });
+    /// 현재 쿠키 저장소에 있는 MYSAPSSO2 토큰 값을 반환합니다.
+    pub fn sso_token(&self) -> Option<String> {
+        self.snapshot()
+            .cookies
+            .into_iter()
+            .find(|cookie| cookie.name == SSO_COOKIE_NAME)
+            .map(|cookie| cookie.value)
+    }
+
+    /// 현재 MYSAPSSO2 토큰을 해석해 사용자 ID, 발급 시스템, 유효 기간 등을 반환합니다.
+    /// 다시 로그인하면 새로 발급된 토큰을 기준으로 해석합니다.
+    pub fn ticket(&self) -> Result<LogonTicket, USaintSessionError> {
+        let token = self
+            .sso_token()
+            .ok_or(USaintSessionError::MissingMYSAPSSO2Cookie)?;
+        Ok(token.parse()?)
+    }
+
+    /// SAP 로그오프 요청을 보내 서버 측 세션을 종료하고 쿠키 저장소를 비웁니다.
+    /// 로그오프 요청이 실패해도 쿠키 저장소는 비운 뒤 첫 번째 오류를 반환합니다.
+    /// 이 세션으로 만든 애플리케이션은 먼저 `Application::close`로 닫는 것이 좋습니다.
+    pub async fn logout(&self) -> Result<(), USaintSessionError> {
+        let mut result = Ok(());
+        for base_url in [&self.config.login_url, &self.config.webdynpro_base_url] {
+            let Ok(logoff_url) = Url::parse(base_url).and_then(|url| url.join(SAP_LOGOFF_PATH))
+            else {
+                continue;
+            };
+            if let Err(error) = self.client.get(logoff_url).send().await {
+                result = result.and(Err(error.into()));
             }
         }
 
+        // 서버에 로그오프하지 못했더라도 SSO 티켓이 클라이언트에 남지 않도록 항상 비움
+        self.cookie_store.clear();
+
+        result
+    }
+
+    /// 주어진 주기마다 세션 유지 요청을 보내는 백그라운드 작업을 시작합니다.
+    /// 등록된 애플리케이션마다 ClientInspector 알림을 보내고, 애플리케이션이 없으면 SSO 세션만 확인합니다.
+    /// 실패는 반환된 `KeepAlive`의 `next_failure`로 전달됩니다.
+    pub fn start_keepalive(self: &Arc<Self>, period: Duration) -> KeepAlive {
+        KeepAlive::start(self, period)
+    }
+
+    /// 세션이 만료되었을 때 다시 로그인할 계정 정보가 있는지 확인합니다.
+    /// 토큰이나 저장된 세션으로 만든 세션은 계정 정보가 없어 다시 인증할 수 없습니다.
+    pub fn can_reauthenticate(&self) -> bool {
+        self.credentials.is_some()
+    }
+
+    /// 다시 인증할 때 사용하는 로그인 방식을 반환합니다.
+    pub fn login_strategy(&self) -> LoginStrategy {
+        self.login_strategy
+    }
+
+    /// 연속 로그인 실패를 기록하는 가드를 반환합니다.
+    pub fn login_guard(&self) -> &Arc<LoginGuard> {
+        &self.login_guard
+    }
+
+    /// 세션이 사용하는 서버 설정을 반환합니다.
+    pub fn config(&self) -> &USaintConfig {
+        &self.config
+    }
+
+    async fn create_session(
+        client: Client,
+        cookie_store: Arc<SessionCookieStore>,
+        credentials: Credentials,
+        login_strategy: LoginStrategy,
+        login_guard: Arc<LoginGuard>,
+        config: USaintConfig,
+    ) -> Result<Self, USaintSessionError> {
+        // SAP SSO 토큰 발급
+        Self::fetch_sso_token(
+            &client,
+            &cookie_store,
+            &config,
+            login_strategy,
+            &login_guard,
+            &credentials,
+        )
+        .await?;
+
+        Self::logged_in(
+            client,
+            cookie_store,
+            credentials,
+            login_strategy,
+            login_guard,
+            config,
+        )
+    }
+
+    async fn create_session_with_password_change(
+        client: Client,
+        cookie_store: Arc<SessionCookieStore>,
+        credentials: Credentials,
+        new_password: Zeroizing<String>,
+        login_strategy: LoginStrategy,
+        login_guard: Arc<LoginGuard>,
+        config: USaintConfig,
+    ) -> Result<Self, USaintSessionError> {
+        // 비밀번호 변경 화면은 SAP 로그온 폼에서만 내려옴
+        if login_strategy != LoginStrategy::SapLogonForm {
+            return Err(USaintSessionError::PasswordChangeUnsupported(
+                login_strategy,
+            ));
+        }
+
+        let attempt = login_guard.begin(credentials.id())?;
+        let result =
+            password::change_expired_password(&client, &config, &credentials, &new_password)
+                .await
+                .and_then(|()| require_sso_cookie(&cookie_store, &config));
+        // 상태 파일 저장 오류보다 비밀번호 변경 자체의 오류를 먼저 반환
+        let recorded = attempt.finish(&result);
+        result?;
+        recorded?;
+
+        // 이후 재인증에는 변경된 비밀번호를 사용
+        Self::logged_in(
+            client,
+            cookie_store,
+            Credentials::new(credentials.id(), new_password.as_str()),
+            login_strategy,
+            login_guard,
+            config,
+        )
+    }
+
+    /// 로그인 요청을 마친 클라이언트로 세션을 만듭니다.
+    fn logged_in(
+        client: Client,
+        cookie_store: Arc<SessionCookieStore>,
+        credentials: Credentials,
+        login_strategy: LoginStrategy,
+        login_guard: Arc<LoginGuard>,
+        config: USaintConfig,
+    ) -> Result<Self, USaintSessionError> {
+        let session = USaintSession {
+            client: Arc::new(client),
+            cookie_store,
+            credentials: Some(credentials),
+            login_strategy,
+            login_guard,
+            config,
+        };
+
+        // 쿠키 저장소에 "MYSAPSSO2" 쿠키가 있는지 확인
+        if session.has_sso_cookie() {
+            return Ok(session);
+        }
+
         Err(USaintSessionError::MissingMYSAPSSO2Cookie)
     }
 
+    fn has_sso_cookie(&self) -> bool {
+        has_sso_cookie(&self.cookie_store, &self.config)
+    }
+
+    /// 주어진 로그인 방식으로 SAP SSO 토큰을 발급받습니다.
+    /// 연속 실패 횟수가 한도에 도달한 계정은 서버에 요청하지 않고 거부하며,
+    /// 요청이 오류 없이 끝나도 MYSAPSSO2 쿠키가 발급되지 않았으면 실패로 기록합니다.
     async fn fetch_sso_token(
         client: &Client,
+        cookie_store: &SessionCookieStore,
+        config: &USaintConfig,
+        login_strategy: LoginStrategy,
+        login_guard: &LoginGuard,
+        credentials: &Credentials,
+    ) -> Result<(), USaintSessionError> {
+        let attempt = login_guard.begin(credentials.id())?;
+
+        let result = match login_strategy {
+            LoginStrategy::SapLogonForm => {
+                Self::fetch_sso_token_from_logon_form(client, config, credentials).await
+            }
+            LoginStrategy::SmartId => smartid::fetch_sso_token(client, config, credentials).await,
+        }
+        .and_then(|()| require_sso_cookie(cookie_store, config));
+
+        // 상태 파일 저장 오류보다 로그인 자체의 오류를 먼저 반환
+        let recorded = attempt.finish(&result);
+        result?;
+        recorded
+    }
+
+    /// SAP 로그인 폼에 계정 정보를 전송합니다.
+    /// 로그인 페이지가 오류 메시지와 함께 다시 내려오면 실패 사유에 맞는 오류를 반환합니다.
+    async fn fetch_sso_token_from_logon_form(
+        client: &Client,
+        config: &USaintConfig,
         credentials: &Credentials,
-    ) -> ReqwestResult<Response> {
+    ) -> Result<(), USaintSessionError> {
         let form_data = [
-            ("sap-user", credentials.id.as_str()),
-            ("sap-password", credentials.password.as_str()),
+            ("sap-user", credentials.id()),
+            ("sap-password", credentials.password()),
             ("sap-system-login", "onLogin"),
         ];
 
         let response = client
-            .post(SAP_LOGIN_FORM_REQUEST_URL)
+            .post(&config.login_url)
             .form(&form_data)
             .send()
             .await?;
 
-        Ok(response)
+        let status = response.status();
+        let body = response.text().await?;
+
+        if let Some(error) = login::parse_login_failure(status, &body) {
+            return Err(error);
+        }
+
+        // 오류 메시지 없이 로그인 폼이 다시 내려오면 SSO 토큰이 발급되지 않은 것으로 판단
+        if is_login_page(&body) {
+            return Err(USaintSessionError::MissingMYSAPSSO2Cookie);
+        }
+
+        Ok(())
+    }
+}
+
+/// 쿠키 저장소에 로그인 주소로 보낼 MYSAPSSO2 쿠키가 있는지 확인합니다.
+fn has_sso_cookie(cookie_store: &SessionCookieStore, config: &USaintConfig) -> bool {
+    let Ok(parsed_url) = Url::parse(&config.login_url) else {
+        return false;
+    };
+
+    cookie_store
+        .cookies(&parsed_url)
+        .and_then(|cookie| cookie.to_str().ok().map(|c| c.contains(SSO_COOKIE_NAME)))
+        .unwrap_or(false)
+}
+
+/// MYSAPSSO2 쿠키가 없으면 `MissingMYSAPSSO2Cookie` 오류를 반환합니다.
+fn require_sso_cookie(
+    cookie_store: &SessionCookieStore,
+    config: &USaintConfig,
+) -> Result<(), USaintSessionError> {
+    if has_sso_cookie(cookie_store, config) {
+        Ok(())
+    } else {
+        Err(USaintSessionError::MissingMYSAPSSO2Cookie)
     }
 }
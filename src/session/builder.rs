@@ -0,0 +1,241 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT},
+    Certificate, Client, Proxy,
+};
+use zeroize::Zeroizing;
+
+use super::{
+    cookies::SessionCookieStore,
+    credentials::{CredentialProvider, Credentials, EnvCredentialProvider},
+    lockout::LoginGuard,
+    store::SessionSnapshot,
+    LoginStrategy, USaintSession, USaintSessionError, DEFAULT_USER_AGENT, SSO_COOKIE_NAME,
+};
+use crate::config::USaintConfig;
+
+/// HTTP 클라이언트 옵션(타임아웃, 프록시, 인증서, 헤더)을 지정해 `USaintSession`을 생성하는 빌더
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use mini_rusaint::session::builder::USaintSessionBuilder;
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let session = USaintSessionBuilder::new()
+///     .connect_timeout(Duration::from_secs(5))
+///     .read_timeout(Duration::from_secs(30))
+///     .proxy(reqwest::Proxy::all("socks5://127.0.0.1:1080")?)
+///     .with_env()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct USaintSessionBuilder {
+    config: USaintConfig,
+    login_strategy: LoginStrategy,
+    login_guard: Option<Arc<LoginGuard>>,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+}
+
+impl USaintSessionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 접속할 서버 설정을 지정합니다.
+    pub fn config(mut self, config: USaintConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// SAP 로그온 티켓을 발급받는 방식을 지정합니다. 기본값은 `LoginStrategy::SapLogonForm`입니다.
+    pub fn login_strategy(mut self, login_strategy: LoginStrategy) -> Self {
+        self.login_strategy = login_strategy;
+        self
+    }
+
+    /// 연속 로그인 실패를 기록할 가드를 지정합니다.
+    /// 지정하지 않으면 프로세스 전역 가드(`LoginGuard::shared`)를 사용합니다.
+    pub fn login_guard(mut self, login_guard: Arc<LoginGuard>) -> Self {
+        self.login_guard = Some(login_guard);
+        self
+    }
+
+    /// 서버와 연결을 맺을 때까지 기다리는 최대 시간을 지정합니다.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 응답을 읽는 동안 데이터가 오지 않을 때 기다리는 최대 시간을 지정합니다.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// HTTP 또는 SOCKS 프록시를 추가합니다.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// 신뢰할 루트 인증서를 추가합니다. (예: 가로채기 프록시의 CA 인증서)
+    pub fn root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// 기본 User-Agent 대신 사용할 값을 지정합니다.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// 모든 요청에 포함할 헤더를 추가합니다.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// 주어진 유세인트 아이디와 비밀번호로 세션을 생성합니다.
+    pub async fn with_password(
+        self,
+        id: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Result<USaintSession, USaintSessionError> {
+        self.with_credentials(Credentials::new(id, password)).await
+    }
+
+    /// 환경 변수에서 유세인트 아이디와 비밀번호를 읽어 세션을 생성합니다.
+    pub async fn with_env(self) -> Result<USaintSession, USaintSessionError> {
+        self.with_provider(&EnvCredentialProvider).await
+    }
+
+    /// 주어진 `CredentialProvider`에서 계정 정보를 가져와 세션을 생성합니다.
+    pub async fn with_provider(
+        self,
+        provider: &impl CredentialProvider,
+    ) -> Result<USaintSession, USaintSessionError> {
+        let credentials = provider.credentials()?;
+        self.with_credentials(credentials).await
+    }
+
+    /// 주어진 계정 정보로 세션을 생성합니다.
+    pub async fn with_credentials(
+        self,
+        credentials: Credentials,
+    ) -> Result<USaintSession, USaintSessionError> {
+        let cookie_store = Arc::new(SessionCookieStore::default());
+        let client = self.build_client(cookie_store.clone())?;
+        USaintSession::create_session(
+            client,
+            cookie_store,
+            credentials,
+            self.login_strategy,
+            self.login_guard.unwrap_or_else(LoginGuard::shared),
+            self.config,
+        )
+        .await
+    }
+
+    /// 만료된 비밀번호를 새 비밀번호로 변경하면서 세션을 생성합니다.
+    /// 새 비밀번호가 서버의 정책에 맞지 않으면 `USaintSessionError::PasswordPolicy`를 반환합니다.
+    /// 비밀번호 변경은 `LoginStrategy::SapLogonForm` 방식에서만 지원합니다.
+    pub async fn with_password_change(
+        self,
+        id: impl Into<String>,
+        old_password: impl Into<String>,
+        new_password: impl Into<String>,
+    ) -> Result<USaintSession, USaintSessionError> {
+        let old_password = Zeroizing::new(old_password.into());
+        let new_password = Zeroizing::new(new_password.into());
+        let cookie_store = Arc::new(SessionCookieStore::default());
+        let client = self.build_client(cookie_store.clone())?;
+        USaintSession::create_session_with_password_change(
+            client,
+            cookie_store,
+            Credentials::new(id, old_password.as_str()),
+            new_password,
+            self.login_strategy,
+            self.login_guard.unwrap_or_else(LoginGuard::shared),
+            self.config,
+        )
+        .await
+    }
+
+    /// 저장된 세션 스냅샷으로부터 세션을 복원합니다.
+    /// 지정한 로그인 방식과 가드를 그대로 사용하지만, 스냅샷에는 계정 정보가 없으므로
+    /// 복원한 세션이 만료되면 다시 인증할 수 없습니다.
+    pub async fn from_snapshot(
+        self,
+        snapshot: &SessionSnapshot,
+    ) -> Result<USaintSession, USaintSessionError> {
+        let cookie_store = Arc::new(SessionCookieStore::default());
+        let client = self.build_client(cookie_store.clone())?;
+        USaintSession::restore_session(
+            client,
+            cookie_store,
+            snapshot,
+            self.login_strategy,
+            self.login_guard.unwrap_or_else(LoginGuard::shared),
+            self.config,
+        )
+        .await
+    }
+
+    /// 이미 발급받은 MYSAPSSO2 토큰으로 비밀번호 없이 세션을 생성합니다.
+    /// 계정 정보가 없는 세션이므로 토큰이 만료되면 다시 인증할 수 없습니다.
+    pub async fn with_token(self, token: &str) -> Result<USaintSession, USaintSessionError> {
+        let mut snapshot = SessionSnapshot::default();
+        for url in [&self.config.login_url, &self.config.webdynpro_base_url] {
+            snapshot.add_cookie(url, SSO_COOKIE_NAME, token);
+        }
+
+        self.from_snapshot(&snapshot)
+            .await
+            .map_err(|error| match error {
+                USaintSessionError::InvalidStoredSession => USaintSessionError::InvalidToken,
+                error => error,
+            })
+    }
+
+    fn build_client(
+        &self,
+        cookie_store: Arc<SessionCookieStore>,
+    ) -> Result<Client, USaintSessionError> {
+        // 기본 헤더 설정
+        let mut headers = self.default_headers.clone();
+
+        let user_agent = match &self.user_agent {
+            Some(user_agent) => HeaderValue::from_str(user_agent)
+                .map_err(|_| USaintSessionError::InvalidUserAgent(user_agent.clone()))?,
+            None => HeaderValue::from_static(DEFAULT_USER_AGENT),
+        };
+        headers.insert(USER_AGENT, user_agent);
+
+        let mut builder = Client::builder()
+            .default_headers(headers)
+            .cookie_provider(cookie_store);
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        for proxy in &self.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+
+        Ok(builder.build()?)
+    }
+}
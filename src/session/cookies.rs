@@ -0,0 +1,73 @@
+use std::sync::RwLock;
+
+use cookie_store::RawCookie;
+use reqwest::{cookie::CookieStore, header::HeaderValue, Url};
+
+use super::store::StoredCookie;
+
+/// 세션의 HTTP 클라이언트가 사용하는 쿠키 저장소
+///
+/// `reqwest::cookie::Jar`와 같은 방식으로 쿠키를 저장하지만, 로그아웃할 때
+/// `Domain`이나 `Path` 속성과 관계없이 저장된 모든 쿠키를 한 번에 비울 수 있습니다.
+#[derive(Debug, Default)]
+pub struct SessionCookieStore(RwLock<cookie_store::CookieStore>);
+
+impl SessionCookieStore {
+    /// `Set-Cookie` 헤더 형식의 쿠키 하나를 주어진 URL의 응답으로 받은 것처럼 저장합니다.
+    pub fn add_cookie_str(&self, cookie: &str, url: &Url) {
+        let cookies = RawCookie::parse(cookie)
+            .ok()
+            .map(|cookie| cookie.into_owned())
+            .into_iter();
+        self.0.write().unwrap().store_response_cookies(cookies, url);
+    }
+
+    /// 주어진 URL로 보내는 요청에 포함될 쿠키를 `Domain`, `Path`, `Secure` 속성과 함께 반환합니다.
+    pub fn stored_cookies(&self, url: &Url) -> Vec<StoredCookie> {
+        self.0
+            .read()
+            .unwrap()
+            .matches(url)
+            .into_iter()
+            .map(|cookie| StoredCookie {
+                url: url.to_string(),
+                name: cookie.name().to_string(),
+                value: cookie.value().to_string(),
+                domain: cookie.domain().map(str::to_string),
+                path: Some(cookie.path.to_string()),
+                secure: cookie.secure().unwrap_or(false),
+            })
+            .collect()
+    }
+
+    /// 저장된 모든 쿠키를 삭제합니다.
+    pub fn clear(&self) {
+        self.0.write().unwrap().clear();
+    }
+}
+
+impl CookieStore for SessionCookieStore {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let cookies = cookie_headers
+            .filter_map(|header| header.to_str().ok())
+            .filter_map(|header| RawCookie::parse(header.to_string()).ok());
+        self.0.write().unwrap().store_response_cookies(cookies, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let header = self
+            .0
+            .read()
+            .unwrap()
+            .get_request_values(url)
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if header.is_empty() {
+            return None;
+        }
+
+        HeaderValue::from_str(&header).ok()
+    }
+}
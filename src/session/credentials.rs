@@ -0,0 +1,226 @@
+use std::{
+    env,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use dotenv::dotenv;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+const ENV_ID_KEY: &str = "USAINT_ID";
+const ENV_PASSWORD_KEY: &str = "USAINT_PASSWORD";
+const SALT_LENGTH: usize = 16;
+
+#[derive(Debug, Error)]
+pub enum CredentialError {
+    #[error("환경 변수 오류: {0}")]
+    EnvVarError(#[from] env::VarError),
+    #[error("입출력 오류: {0}")]
+    IoError(#[from] io::Error),
+    #[error("계정 정보 파일 형식 오류: {0}")]
+    SerializationError(#[from] serde_json::Error),
+    #[error("계정 정보 파일을 복호화할 수 없습니다. 암호가 올바른지 확인하세요.")]
+    DecryptionFailed,
+    #[error("계정 정보를 암호화할 수 없습니다.")]
+    EncryptionFailed,
+    #[error("계정 정보를 가져오지 못했습니다: {0}")]
+    ProviderFailed(String),
+}
+
+/// 유세인트 아이디(학번)와 비밀번호
+/// 메모리에서 해제될 때 내용이 0으로 덮어쓰이며, `Debug` 출력에서 비밀번호는 가려집니다.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Credentials {
+    id: String,
+    password: String,
+}
+
+impl Credentials {
+    pub fn new(id: impl Into<String>, password: impl Into<String>) -> Self {
+        Credentials {
+            id: id.into(),
+            password: password.into(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+}
+
+impl Debug for Credentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Credentials")
+            .field("id", &self.id)
+            .field("password", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// 세션 생성에 사용할 계정 정보를 제공하는 트레이트
+pub trait CredentialProvider {
+    fn credentials(&self) -> Result<Credentials, CredentialError>;
+}
+
+/// `USAINT_ID`, `USAINT_PASSWORD` 환경 변수(`.env` 파일 포함)에서 계정 정보를 읽습니다.
+#[derive(Debug, Default)]
+pub struct EnvCredentialProvider;
+
+impl CredentialProvider for EnvCredentialProvider {
+    fn credentials(&self) -> Result<Credentials, CredentialError> {
+        dotenv().ok();
+        let id = env::var(ENV_ID_KEY)?;
+        let password = Zeroizing::new(env::var(ENV_PASSWORD_KEY)?);
+        Ok(Credentials::new(id, password.as_str()))
+    }
+}
+
+/// 터미널에서 아이디와 비밀번호를 입력받습니다. 비밀번호는 화면에 표시되지 않습니다.
+#[derive(Debug, Default)]
+pub struct PromptCredentialProvider;
+
+impl CredentialProvider for PromptCredentialProvider {
+    fn credentials(&self) -> Result<Credentials, CredentialError> {
+        let mut stderr = io::stderr();
+        write!(stderr, "유세인트 아이디(학번): ")?;
+        stderr.flush()?;
+
+        let mut id = String::new();
+        io::stdin().lock().read_line(&mut id)?;
+
+        let password = Zeroizing::new(rpassword::prompt_password("유세인트 비밀번호: ")?);
+
+        Ok(Credentials::new(id.trim(), password.as_str()))
+    }
+}
+
+/// 호출자가 넘겨준 클로저로부터 계정 정보를 가져옵니다.
+pub struct FnCredentialProvider<F>(F);
+
+impl<F> FnCredentialProvider<F>
+where
+    F: Fn() -> Result<Credentials, CredentialError>,
+{
+    pub fn new(f: F) -> Self {
+        FnCredentialProvider(f)
+    }
+}
+
+impl<F> CredentialProvider for FnCredentialProvider<F>
+where
+    F: Fn() -> Result<Credentials, CredentialError>,
+{
+    fn credentials(&self) -> Result<Credentials, CredentialError> {
+        (self.0)()
+    }
+}
+
+/// 암호화된 계정 정보 파일의 형식
+#[derive(Serialize, Deserialize)]
+struct EncryptedCredentialFile {
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Zeroize, ZeroizeOnDrop)]
+struct PlainCredentials {
+    id: String,
+    password: String,
+}
+
+/// 암호(passphrase)로 암호화된 파일에서 계정 정보를 읽습니다.
+/// 파일은 Argon2로 유도한 키와 ChaCha20-Poly1305로 암호화됩니다.
+pub struct EncryptedFileCredentialProvider {
+    path: PathBuf,
+    passphrase: Zeroizing<String>,
+}
+
+impl EncryptedFileCredentialProvider {
+    pub fn new(path: impl AsRef<Path>, passphrase: impl Into<String>) -> Self {
+        EncryptedFileCredentialProvider {
+            path: path.as_ref().to_path_buf(),
+            passphrase: Zeroizing::new(passphrase.into()),
+        }
+    }
+
+    /// 주어진 계정 정보를 암호화해 파일에 저장합니다.
+    pub fn write(&self, credentials: &Credentials) -> Result<(), CredentialError> {
+        let mut salt = [0u8; SALT_LENGTH];
+        OsRng.fill_bytes(&mut salt);
+
+        let cipher = self.cipher(&salt)?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let plaintext = Zeroizing::new(serde_json::to_vec(&PlainCredentials {
+            id: credentials.id.clone(),
+            password: credentials.password.clone(),
+        })?);
+
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| CredentialError::EncryptionFailed)?;
+
+        let file = EncryptedCredentialFile {
+            salt: BASE64.encode(salt),
+            nonce: BASE64.encode(nonce),
+            ciphertext: BASE64.encode(ciphertext),
+        };
+
+        std::fs::write(&self.path, serde_json::to_string_pretty(&file)?)?;
+
+        Ok(())
+    }
+
+    fn cipher(&self, salt: &[u8]) -> Result<ChaCha20Poly1305, CredentialError> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), salt, key.as_mut())
+            .map_err(|_| CredentialError::EncryptionFailed)?;
+
+        Ok(ChaCha20Poly1305::new(Key::from_slice(key.as_ref())))
+    }
+}
+
+impl CredentialProvider for EncryptedFileCredentialProvider {
+    fn credentials(&self) -> Result<Credentials, CredentialError> {
+        let contents = std::fs::read_to_string(&self.path)?;
+        let file: EncryptedCredentialFile = serde_json::from_str(&contents)?;
+
+        let decode = |value: &str| {
+            BASE64
+                .decode(value)
+                .map_err(|_| CredentialError::DecryptionFailed)
+        };
+        let salt = decode(&file.salt)?;
+        let nonce = decode(&file.nonce)?;
+        let ciphertext = decode(&file.ciphertext)?;
+
+        if nonce.len() != 12 {
+            return Err(CredentialError::DecryptionFailed);
+        }
+
+        let plaintext = Zeroizing::new(
+            self.cipher(&salt)?
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+                .map_err(|_| CredentialError::DecryptionFailed)?,
+        );
+
+        let plain: PlainCredentials = serde_json::from_slice(&plaintext)?;
+
+        Ok(Credentials::new(plain.id.as_str(), plain.password.as_str()))
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use thiserror::Error;
+use tokio::{
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    task::JoinHandle,
+    time::{self, MissedTickBehavior},
+};
+
+use super::{USaintSession, USaintSessionError};
+use crate::applications::{Application, ApplicationError};
+
+#[derive(Debug, Error)]
+pub enum KeepAliveError {
+    #[error("{app_name} 애플리케이션의 세션 유지에 실패했습니다: {error}")]
+    Application {
+        app_name: String,
+        error: ApplicationError,
+    },
+    #[error("세션 유지 요청에 실패했습니다: {0}")]
+    Session(#[from] USaintSessionError),
+    #[error("SSO 세션이 만료되었습니다.")]
+    SessionExpired,
+}
+
+type KeepAliveTarget = Weak<dyn AsRef<Application> + Send + Sync>;
+
+/// 백그라운드에서 실행 중인 세션 유지 작업의 핸들
+/// 핸들이 drop되면 작업도 함께 중지됩니다.
+pub struct KeepAlive {
+    applications: Arc<Mutex<Vec<KeepAliveTarget>>>,
+    failures: UnboundedReceiver<KeepAliveError>,
+    task: JoinHandle<()>,
+}
+
+impl KeepAlive {
+    pub(super) fn start(session: &Arc<USaintSession>, period: Duration) -> Self {
+        let applications: Arc<Mutex<Vec<KeepAliveTarget>>> = Arc::new(Mutex::new(Vec::new()));
+        let (sender, failures) = mpsc::unbounded_channel();
+
+        let task = tokio::spawn(Self::run(
+            Arc::downgrade(session),
+            applications.clone(),
+            period,
+            sender,
+        ));
+
+        KeepAlive {
+            applications,
+            failures,
+            task,
+        }
+    }
+
+    /// 세션 유지 요청을 보낼 애플리케이션을 등록합니다.
+    /// 애플리케이션이 drop되면 자동으로 대상에서 제외됩니다.
+    pub fn add_application<T>(&self, application: &Arc<T>)
+    where
+        T: AsRef<Application> + Send + Sync + 'static,
+    {
+        let application: Arc<dyn AsRef<Application> + Send + Sync> = application.clone();
+        self.applications
+            .lock()
+            .unwrap()
+            .push(Arc::downgrade(&application));
+    }
+
+    /// 다음 세션 유지 실패를 기다립니다. 작업이 중지되면 `None`을 반환합니다.
+    pub async fn next_failure(&mut self) -> Option<KeepAliveError> {
+        self.failures.recv().await
+    }
+
+    /// 이미 발생한 세션 유지 실패가 있으면 기다리지 않고 반환합니다.
+    pub fn try_next_failure(&mut self) -> Option<KeepAliveError> {
+        self.failures.try_recv().ok()
+    }
+
+    /// 세션 유지 작업을 중지합니다.
+    pub fn stop(self) {}
+
+    async fn run(
+        session: Weak<USaintSession>,
+        applications: Arc<Mutex<Vec<KeepAliveTarget>>>,
+        period: Duration,
+        failures: UnboundedSender<KeepAliveError>,
+    ) {
+        let mut interval = time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // 첫 tick은 즉시 완료되므로 건너뜀
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            // 세션이 drop되면 작업 종료
+            let Some(session) = session.upgrade() else {
+                break;
+            };
+
+            let targets: Vec<_> = {
+                let mut applications = applications.lock().unwrap();
+                applications.retain(|application| application.strong_count() > 0);
+                applications
+                    .iter()
+                    .filter_map(|application| application.upgrade())
+                    .collect()
+            };
+
+            // 열린 애플리케이션이 없으면 SSO 세션만 확인
+            if targets.is_empty() {
+                let failure = match session.is_valid().await {
+                    Ok(true) => None,
+                    Ok(false) => Some(KeepAliveError::SessionExpired),
+                    Err(error) => Some(KeepAliveError::Session(error)),
+                };
+                if let Some(failure) = failure {
+                    if failures.send(failure).is_err() {
+                        break;
+                    }
+                }
+                continue;
+            }
+
+            for target in targets {
+                let application = (*target).as_ref();
+                if let Err(error) = application.keep_alive().await {
+                    let failure = KeepAliveError::Application {
+                        app_name: application.app_name().to_string(),
+                        error,
+                    };
+                    if failures.send(failure).is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for KeepAlive {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use super::USaintSessionError;
+
+/// 기본 허용 연속 실패 횟수
+/// 서버의 계정 잠금 기준(보통 5회)보다 작게 잡아 사람이 직접 로그인할 여유를 남깁니다.
+pub const DEFAULT_MAX_FAILURES: u32 = 3;
+
+/// 계정별 연속 로그인 실패 횟수를 기록해, 서버에서 계정이 잠기기 전에 로그인 시도를 막습니다.
+///
+/// 실패 횟수가 한도에 도달하면 `reset`을 호출하기 전까지 해당 계정의 로그인은
+/// 서버에 요청을 보내지 않고 `USaintSessionError::TooManyFailedLogins`로 거부됩니다.
+/// 로그인에 성공하면 실패 횟수는 0으로 초기화됩니다.
+///
+/// 결과를 기다리는 중인 로그인 시도도 실패할 수 있는 횟수로 미리 셈하므로,
+/// 같은 계정으로 동시에 로그인해도 서버에 보내는 시도는 남은 허용 횟수를 넘지 않습니다.
+#[derive(Debug)]
+pub struct LoginGuard {
+    max_failures: u32,
+    failures: Mutex<HashMap<String, u32>>,
+    in_flight: Mutex<HashMap<String, u32>>,
+    state_file: Option<PathBuf>,
+}
+
+/// 결과를 기다리는 로그인 시도 하나
+///
+/// `finish`로 결과를 기록하지 않고 버려지면(예: 요청이 취소된 경우) 실패로 세지 않고 시도만 취소합니다.
+#[derive(Debug)]
+pub(super) struct LoginAttempt<'a> {
+    guard: &'a LoginGuard,
+    id: String,
+    finished: bool,
+}
+
+/// 로그인 결과가 실패 횟수에 미치는 영향
+enum LoginOutcome {
+    /// 성공: 실패 횟수를 초기화
+    Succeeded,
+    /// 계정 정보로 인한 실패: 실패 횟수에 더함
+    Failed,
+    /// 네트워크 오류 등 계정과 관계없는 결과: 실패 횟수를 바꾸지 않음
+    Ignored,
+}
+
+impl LoginGuard {
+    /// 최대 `max_failures`번까지 연속 실패를 허용하는 메모리 기반 가드를 생성합니다.
+    pub fn new(max_failures: u32) -> Self {
+        LoginGuard {
+            max_failures: max_failures.max(1),
+            failures: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+            state_file: None,
+        }
+    }
+
+    /// 실패 횟수를 주어진 파일에 저장하는 가드를 생성합니다.
+    /// 파일이 이미 있으면 저장된 실패 횟수를 불러오므로 프로세스를 다시 시작해도 기록이 유지됩니다.
+    pub fn with_state_file(
+        max_failures: u32,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, USaintSessionError> {
+        let path = path.as_ref().to_path_buf();
+        let failures = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(LoginGuard {
+            max_failures: max_failures.max(1),
+            failures: Mutex::new(failures),
+            in_flight: Mutex::new(HashMap::new()),
+            state_file: Some(path),
+        })
+    }
+
+    /// 별도로 지정하지 않은 모든 세션이 함께 사용하는 프로세스 전역 가드를 반환합니다.
+    pub fn shared() -> Arc<LoginGuard> {
+        static SHARED: OnceLock<Arc<LoginGuard>> = OnceLock::new();
+        SHARED
+            .get_or_init(|| Arc::new(LoginGuard::new(DEFAULT_MAX_FAILURES)))
+            .clone()
+    }
+
+    /// 허용하는 최대 연속 실패 횟수
+    pub fn max_failures(&self) -> u32 {
+        self.max_failures
+    }
+
+    /// 주어진 계정의 현재 연속 실패 횟수를 반환합니다.
+    pub fn failures(&self, id: &str) -> u32 {
+        self.failures.lock().unwrap().get(id).copied().unwrap_or(0)
+    }
+
+    /// 주어진 계정의 로그인 시도가 허용되는지 확인합니다.
+    pub fn check(&self, id: &str) -> Result<(), USaintSessionError> {
+        let failures = self.failures(id);
+        if failures >= self.max_failures {
+            return Err(USaintSessionError::TooManyFailedLogins {
+                id: id.to_string(),
+                failures,
+            });
+        }
+        Ok(())
+    }
+
+    /// 주어진 계정의 실패 횟수를 초기화해 다시 로그인을 시도할 수 있게 합니다.
+    pub fn reset(&self, id: &str) -> Result<(), USaintSessionError> {
+        let mut failures = self.failures.lock().unwrap();
+        if failures.remove(id).is_some() {
+            self.save(&failures)?;
+        }
+        Ok(())
+    }
+
+    /// 로그인 시도를 시작합니다.
+    /// 기록된 실패와 결과를 기다리는 시도를 합쳐 한도에 도달했으면 거부하고, 아니면 시도 하나를 예약합니다.
+    /// 확인과 예약은 하나의 잠금 안에서 이루어집니다.
+    pub(super) fn begin(&self, id: &str) -> Result<LoginAttempt<'_>, USaintSessionError> {
+        let failures = self.failures.lock().unwrap();
+        let mut in_flight = self.in_flight.lock().unwrap();
+
+        let recorded = failures.get(id).copied().unwrap_or(0);
+        let pending = in_flight.entry(id.to_string()).or_insert(0);
+        if recorded + *pending >= self.max_failures {
+            if *pending == 0 {
+                in_flight.remove(id);
+            }
+            return Err(USaintSessionError::TooManyFailedLogins {
+                id: id.to_string(),
+                failures: recorded,
+            });
+        }
+        *pending += 1;
+
+        Ok(LoginAttempt {
+            guard: self,
+            id: id.to_string(),
+            finished: false,
+        })
+    }
+
+    /// 예약한 시도를 끝내고, 주어진 결과를 실패 횟수에 반영합니다.
+    fn complete(&self, id: &str, outcome: LoginOutcome) -> Result<(), USaintSessionError> {
+        let mut failures = self.failures.lock().unwrap();
+        let mut in_flight = self.in_flight.lock().unwrap();
+
+        if let Some(pending) = in_flight.get_mut(id) {
+            *pending = pending.saturating_sub(1);
+            if *pending == 0 {
+                in_flight.remove(id);
+            }
+        }
+
+        match outcome {
+            LoginOutcome::Failed => {
+                *failures.entry(id.to_string()).or_insert(0) += 1;
+                self.save(&failures)
+            }
+            LoginOutcome::Succeeded if failures.remove(id).is_some() => self.save(&failures),
+            _ => Ok(()),
+        }
+    }
+
+    fn save(&self, failures: &HashMap<String, u32>) -> Result<(), USaintSessionError> {
+        if let Some(path) = &self.state_file {
+            std::fs::write(path, serde_json::to_string_pretty(failures)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for LoginGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FAILURES)
+    }
+}
+
+impl LoginAttempt<'_> {
+    /// 로그인 결과를 기록합니다. 성공하면 실패 횟수를 초기화하고, 계정 정보로 인한 실패만 횟수에 더합니다.
+    ///
+    /// 로그인 요청에 대한 `MissingMYSAPSSO2Cookie`(알 수 없는 메시지와 함께 로그인 폼이 다시 내려왔거나
+    /// 티켓이 발급되지 않은 경우)와 `MissingSmartIdToken`(스마트 ID가 메시지 없이 토큰을 발급하지 않은 경우)은
+    /// 실패로 셉니다. 상태 파일을 저장하지 못하면 그 오류를 반환하지만, 호출하는 쪽은
+    /// 로그인 자체의 오류를 먼저 반환해야 합니다.
+    pub(super) fn finish(
+        mut self,
+        result: &Result<(), USaintSessionError>,
+    ) -> Result<(), USaintSessionError> {
+        let outcome = match result {
+            Ok(()) => LoginOutcome::Succeeded,
+            Err(error) if error.is_authentication_failure() => LoginOutcome::Failed,
+            Err(
+                USaintSessionError::MissingMYSAPSSO2Cookie
+                | USaintSessionError::MissingSmartIdToken,
+            ) => LoginOutcome::Failed,
+            Err(_) => LoginOutcome::Ignored,
+        };
+        self.finished = true;
+        self.guard.complete(&self.id, outcome)
+    }
+}
+
+impl Drop for LoginAttempt<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = self.guard.complete(&self.id, LoginOutcome::Ignored);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use reqwest::StatusCode;
+use scraper::{Html, Selector};
+
+use super::USaintSessionError;
+use crate::webdynpro::page::is_login_page;
+
+/// SAP 로그온 페이지에서 오류 메시지가 표시되는 요소들의 선택자
+const LOGIN_MESSAGE_SELECTOR: &str =
+    ".urMsgBarTxt, .urMsgBarTxtStd, .lsMessageBar__text, .sapMessageBarText, [id=\"m1\"]";
+
+const WRONG_PASSWORD_KEYWORDS: [&str; 4] = [
+    "password is incorrect",
+    "incorrect password",
+    "비밀번호가 올바르지",
+    "비밀번호가 일치하지",
+];
+const UNKNOWN_USER_KEYWORDS: [&str; 5] = [
+    "does not exist",
+    "존재하지 않는 아이디",
+    "unknown user",
+    "사용자가 존재하지",
+    "등록되지 않은 사용자",
+];
+const ACCOUNT_LOCKED_KEYWORDS: [&str; 3] = ["is locked", "잠겨", "잠금"];
+const PASSWORD_EXPIRED_KEYWORDS: [&str; 5] = [
+    "password has expired",
+    "password is no longer valid",
+    "change your password",
+    "비밀번호가 만료",
+    "비밀번호를 변경",
+];
+const MAINTENANCE_KEYWORDS: [&str; 4] =
+    ["maintenance", "not available", "시스템 점검", "서비스 점검"];
+
+/// SAP 로그인 요청의 응답을 분석해 로그인 실패 사유를 반환합니다.
+/// 로그인 폼이 오류 메시지와 함께 다시 내려온 경우에만 실패로 판단하며, 그렇지 않으면 `None`을 반환합니다.
+pub fn parse_login_failure(status: StatusCode, body: &str) -> Option<USaintSessionError> {
+    if status == StatusCode::SERVICE_UNAVAILABLE {
+        return Some(USaintSessionError::ServerMaintenance(
+            find_login_message(body).unwrap_or_else(|| status.to_string()),
+        ));
+    }
+
+    if !is_login_page(body) {
+        return None;
+    }
+
+    let message = find_login_message(body)?;
+
+    Some(classify_login_message(message))
+}
+
+/// 로그인 실패 메시지의 내용을 보고 알맞은 오류로 분류합니다.
+pub fn classify_login_message(message: String) -> USaintSessionError {
+    let lowercase_message = message.to_lowercase();
+    let contains_any = |keywords: &[&str]| {
+        keywords
+            .iter()
+            .any(|keyword| lowercase_message.contains(keyword))
+    };
+
+    // 잠금 메시지에 "비밀번호" 문구가 함께 포함되는 경우가 있으므로 잠금 여부를 먼저 확인
+    if contains_any(&ACCOUNT_LOCKED_KEYWORDS) {
+        USaintSessionError::AccountLocked(message)
+    } else if contains_any(&PASSWORD_EXPIRED_KEYWORDS) {
+        USaintSessionError::PasswordExpired(message)
+    } else if contains_any(&UNKNOWN_USER_KEYWORDS) {
+        USaintSessionError::UnknownUser(message)
+    } else if contains_any(&WRONG_PASSWORD_KEYWORDS) {
+        USaintSessionError::WrongPassword(message)
+    } else if contains_any(&MAINTENANCE_KEYWORDS) {
+        USaintSessionError::ServerMaintenance(message)
+    } else {
+        USaintSessionError::LoginFailed(message)
+    }
+}
+
+/// 로그온 페이지에 표시된 오류 메시지를 찾습니다.
+fn find_login_message(body: &str) -> Option<String> {
+    let document = Html::parse_document(body);
+    let message_selector = Selector::parse(LOGIN_MESSAGE_SELECTOR).unwrap();
+
+    document
+        .select(&message_selector)
+        .map(|element| element.text().collect::<String>().trim().to_string())
+        .find(|message| !message.is_empty())
+}
@@ -0,0 +1,203 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Duration;
+use thiserror::Error;
+use tokio::sync::{Mutex, Semaphore};
+
+use super::{
+    builder::USaintSessionBuilder, credentials::CredentialProvider, USaintSession,
+    USaintSessionError,
+};
+use crate::applications::course_grades::{CourseGradesApplication, CourseGradesApplicationError};
+
+/// 티켓 만료 전에 미리 세션을 갱신하는 기본 여유 시간 (분)
+const DEFAULT_REFRESH_MARGIN_MINUTES: i64 = 10;
+
+#[derive(Debug, Error)]
+pub enum SessionManagerError {
+    #[error("등록되지 않은 계정입니다: {0}")]
+    UnknownAccount(String),
+    #[error(
+        "{student_id} 계정의 계정 정보 제공자가 다른 학번({provided})의 계정 정보를 반환했습니다."
+    )]
+    MismatchedCredentials {
+        student_id: String,
+        provided: String,
+    },
+    #[error("세션 오류: {0}")]
+    SessionError(#[from] USaintSessionError),
+    #[error("성적 조회 애플리케이션 오류: {0}")]
+    CourseGradesApplicationError(#[from] CourseGradesApplicationError),
+}
+
+type BuilderFactory = Box<dyn Fn() -> USaintSessionBuilder + Send + Sync>;
+
+struct Account {
+    provider: Box<dyn CredentialProvider + Send + Sync>,
+    session: Mutex<Option<Arc<USaintSession>>>,
+}
+
+/// 여러 학생 계정의 세션을 학번별로 관리합니다.
+/// 세션은 처음 요청될 때 생성되어 캐시되고, 티켓 만료가 가까워지면 다시 로그인합니다.
+/// 계정마다 별도의 쿠키 저장소를 사용하며, 동시에 진행되는 로그인 수를 제한합니다.
+pub struct SessionManager {
+    accounts: Mutex<HashMap<String, Arc<Account>>>,
+    login_permits: Semaphore,
+    builder: BuilderFactory,
+    refresh_margin: Duration,
+}
+
+impl SessionManager {
+    /// 동시에 최대 `max_concurrent_logins`개의 로그인만 진행하는 세션 관리자를 생성합니다.
+    pub fn new(max_concurrent_logins: usize) -> Self {
+        SessionManager {
+            accounts: Mutex::new(HashMap::new()),
+            login_permits: Semaphore::new(max_concurrent_logins.max(1)),
+            builder: Box::new(USaintSessionBuilder::new),
+            refresh_margin: Duration::minutes(DEFAULT_REFRESH_MARGIN_MINUTES),
+        }
+    }
+
+    /// 세션을 생성할 때 사용할 빌더(서버 설정, 타임아웃, 프록시 등)를 지정합니다.
+    pub fn with_builder(
+        mut self,
+        builder: impl Fn() -> USaintSessionBuilder + Send + Sync + 'static,
+    ) -> Self {
+        self.builder = Box::new(builder);
+        self
+    }
+
+    /// 티켓 만료까지 남은 시간이 이 값보다 짧으면 세션을 미리 갱신합니다.
+    pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// 학번과 계정 정보를 제공할 `CredentialProvider`를 등록합니다.
+    /// 이미 등록된 학번이면 기존 세션을 버리고 새 계정 정보로 교체합니다.
+    pub async fn add_account(
+        &self,
+        student_id: impl Into<String>,
+        provider: impl CredentialProvider + Send + Sync + 'static,
+    ) {
+        let account = Arc::new(Account {
+            provider: Box::new(provider),
+            session: Mutex::new(None),
+        });
+        self.accounts
+            .lock()
+            .await
+            .insert(student_id.into(), account);
+    }
+
+    /// 등록된 학번 목록을 반환합니다.
+    pub async fn accounts(&self) -> Vec<String> {
+        self.accounts.lock().await.keys().cloned().collect()
+    }
+
+    /// 주어진 학번의 세션을 반환합니다.
+    /// 캐시된 세션이 없으면 로그인하고, 티켓이 곧 만료되면 다시 로그인합니다.
+    pub async fn session(
+        &self,
+        student_id: &str,
+    ) -> Result<Arc<USaintSession>, SessionManagerError> {
+        let account = self.account(student_id).await?;
+        let mut cached = account.session.lock().await;
+
+        if let Some(session) = cached.as_ref() {
+            if !self.needs_refresh(session) {
+                return Ok(session.clone());
+            }
+
+            let _permit = self.login_permits.acquire().await.unwrap();
+            session.reauthenticate().await?;
+            return Ok(session.clone());
+        }
+
+        let session = Arc::new(self.login(student_id, &account).await?);
+        *cached = Some(session.clone());
+
+        Ok(session)
+    }
+
+    /// 캐시 여부와 관계없이 주어진 학번의 세션을 다시 로그인합니다.
+    pub async fn refresh(
+        &self,
+        student_id: &str,
+    ) -> Result<Arc<USaintSession>, SessionManagerError> {
+        let account = self.account(student_id).await?;
+        let mut cached = account.session.lock().await;
+
+        let session = Arc::new(self.login(student_id, &account).await?);
+        *cached = Some(session.clone());
+
+        Ok(session)
+    }
+
+    /// 캐시된 세션을 버립니다. 계정 등록은 유지되므로 다음 요청 때 다시 로그인합니다.
+    pub async fn evict(&self, student_id: &str) -> Option<Arc<USaintSession>> {
+        let account = self.account(student_id).await.ok()?;
+        let session = account.session.lock().await.take();
+        session
+    }
+
+    /// 캐시된 세션에서 로그아웃하고 계정 등록을 해제합니다.
+    pub async fn remove_account(&self, student_id: &str) -> Result<(), SessionManagerError> {
+        let account = self.accounts.lock().await.remove(student_id);
+
+        if let Some(account) = account {
+            if let Some(session) = account.session.lock().await.take() {
+                session.logout().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 주어진 학번의 세션으로 성적 조회 애플리케이션을 생성합니다.
+    pub async fn course_grades(
+        &self,
+        student_id: &str,
+    ) -> Result<CourseGradesApplication, SessionManagerError> {
+        let session = self.session(student_id).await?;
+        Ok(CourseGradesApplication::with_session(session).await?)
+    }
+
+    async fn account(&self, student_id: &str) -> Result<Arc<Account>, SessionManagerError> {
+        self.accounts
+            .lock()
+            .await
+            .get(student_id)
+            .cloned()
+            .ok_or_else(|| SessionManagerError::UnknownAccount(student_id.to_string()))
+    }
+
+    /// 계정의 계정 정보로 로그인합니다.
+    /// 제공자가 등록된 학번과 다른 계정 정보를 반환하면 다른 학생의 세션이 섞이지 않도록 거부합니다.
+    async fn login(
+        &self,
+        student_id: &str,
+        account: &Account,
+    ) -> Result<USaintSession, SessionManagerError> {
+        let _permit = self.login_permits.acquire().await.unwrap();
+        let credentials = account
+            .provider
+            .credentials()
+            .map_err(USaintSessionError::from)?;
+        if credentials.id() != student_id {
+            return Err(SessionManagerError::MismatchedCredentials {
+                student_id: student_id.to_string(),
+                provided: credentials.id().to_string(),
+            });
+        }
+        Ok((self.builder)().with_credentials(credentials).await?)
+    }
+
+    /// 티켓을 해석할 수 없으면 만료 시각을 알 수 없으므로 갱신하지 않습니다.
+    fn needs_refresh(&self, session: &USaintSession) -> bool {
+        session
+            .ticket()
+            .map(|ticket| ticket.remaining() <= self.refresh_margin)
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,233 @@
+use reqwest::{Client, Url};
+use scraper::{Html, Selector};
+use thiserror::Error;
+
+use super::{credentials::Credentials, login, USaintSessionError};
+use crate::{config::USaintConfig, webdynpro::page::is_login_page};
+
+/// 만료된 비밀번호를 바꿀 때 SAP 로그온 폼에 전송하는 필드 이름
+const NEW_PASSWORD_FIELD: &str = "sap-system-login-password-new";
+const REPEAT_PASSWORD_FIELD: &str = "sap-system-login-password-repeat";
+const PASSWORD_CHANGE_COMMAND: &str = "onPasswordChange";
+
+/// 비밀번호 변경 폼과, 그 폼에 들어 있는 숨은 입력 필드의 선택자
+const PASSWORD_CHANGE_FORM_SELECTOR: &str = "form";
+const NEW_PASSWORD_INPUT_SELECTOR: &str = r#"input[name="sap-system-login-password-new"]"#;
+const HIDDEN_INPUT_SELECTOR: &str = r#"input[type="hidden"]"#;
+
+const MISMATCH_KEYWORDS: [&str; 4] = [
+    "not identical",
+    "do not match",
+    "새 비밀번호가 일치하지",
+    "비밀번호 확인",
+];
+const CHANGE_NOT_ALLOWED_KEYWORDS: [&str; 4] = [
+    "once a day",
+    "cannot be changed",
+    "변경할 수 없",
+    "하루에 한 번",
+];
+const REUSED_KEYWORDS: [&str; 7] = [
+    "different from your last",
+    "one of your last",
+    "already been used",
+    "previous password",
+    "password history",
+    "이전 비밀번호",
+    "최근에 사용",
+];
+const TOO_SIMILAR_KEYWORDS: [&str; 4] = [
+    "must differ",
+    "different from the old",
+    "기존 비밀번호와",
+    "동일한 비밀번호",
+];
+const MISSING_CHARACTER_CLASS_KEYWORDS: [&str; 7] = [
+    "digit",
+    "letter",
+    "special character",
+    "숫자",
+    "영문",
+    "특수문자",
+    "문자 조합",
+];
+const TOO_SHORT_KEYWORDS: [&str; 4] = ["too short", "at least", "너무 짧", "자 이상"];
+
+/// 서버의 비밀번호 정책에 맞지 않아 비밀번호 변경이 거부된 사유
+#[derive(Debug, Error)]
+pub enum PasswordPolicyError {
+    #[error("새 비밀번호가 너무 짧습니다: {0}")]
+    TooShort(String),
+    #[error("새 비밀번호에 필요한 문자 종류가 빠져 있습니다: {0}")]
+    MissingCharacterClass(String),
+    #[error("최근에 사용한 비밀번호는 다시 사용할 수 없습니다: {0}")]
+    RecentlyUsed(String),
+    #[error("새 비밀번호가 기존 비밀번호와 너무 비슷합니다: {0}")]
+    TooSimilar(String),
+    #[error("새 비밀번호와 확인 값이 일치하지 않습니다: {0}")]
+    Mismatch(String),
+    #[error("지금은 비밀번호를 변경할 수 없습니다: {0}")]
+    ChangeNotAllowed(String),
+    #[error("비밀번호 변경이 거부되었습니다: {0}")]
+    Rejected(String),
+}
+
+/// 비밀번호 변경 실패 메시지가 정책 위반이면 알맞은 사유로 분류합니다.
+pub fn classify_policy_message(message: &str) -> Option<PasswordPolicyError> {
+    let lowercase_message = message.to_lowercase();
+    let contains_any = |keywords: &[&str]| {
+        keywords
+            .iter()
+            .any(|keyword| lowercase_message.contains(keyword))
+    };
+    let message = message.to_string();
+
+    // "최근 5개와 다른 비밀번호" 처럼 여러 조건이 함께 언급되는 경우가 있어 구체적인 사유부터 확인
+    if contains_any(&MISMATCH_KEYWORDS) {
+        Some(PasswordPolicyError::Mismatch(message))
+    } else if contains_any(&CHANGE_NOT_ALLOWED_KEYWORDS) {
+        Some(PasswordPolicyError::ChangeNotAllowed(message))
+    } else if contains_any(&REUSED_KEYWORDS) {
+        Some(PasswordPolicyError::RecentlyUsed(message))
+    } else if contains_any(&TOO_SIMILAR_KEYWORDS) {
+        Some(PasswordPolicyError::TooSimilar(message))
+    } else if contains_any(&MISSING_CHARACTER_CLASS_KEYWORDS) {
+        Some(PasswordPolicyError::MissingCharacterClass(message))
+    } else if contains_any(&TOO_SHORT_KEYWORDS) {
+        Some(PasswordPolicyError::TooShort(message))
+    } else {
+        None
+    }
+}
+
+/// 기존 비밀번호로 로그인해 비밀번호 변경 폼을 받은 뒤, 같은 클라이언트로 그 폼에 새 비밀번호(확인 포함)를 전송합니다.
+/// 폼의 숨은 입력 필드(XSRF 토큰 등)는 그대로 함께 보내며, 변경에 성공하면 서버가 MYSAPSSO2 쿠키를 발급합니다.
+pub(super) async fn change_expired_password(
+    client: &Client,
+    config: &USaintConfig,
+    credentials: &Credentials,
+    new_password: &str,
+) -> Result<(), USaintSessionError> {
+    let form = fetch_password_change_form(client, config, credentials).await?;
+
+    let mut form_data = form.hidden_fields;
+    let fields = [
+        ("sap-user", credentials.id()),
+        ("sap-password", credentials.password()),
+        (NEW_PASSWORD_FIELD, new_password),
+        (REPEAT_PASSWORD_FIELD, new_password),
+        ("sap-system-login", PASSWORD_CHANGE_COMMAND),
+    ];
+    form_data.retain(|(name, _)| fields.iter().all(|(field, _)| name != field));
+    form_data.extend(
+        fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string())),
+    );
+
+    let response = client.post(form.action).form(&form_data).send().await?;
+
+    let status = response.status();
+    let body = response.text().await?;
+
+    if let Some(error) = login::parse_login_failure(status, &body) {
+        return Err(match error {
+            USaintSessionError::LoginFailed(message)
+            | USaintSessionError::PasswordExpired(message) => {
+                match classify_policy_message(&message) {
+                    Some(policy_error) => policy_error.into(),
+                    None => PasswordPolicyError::Rejected(message).into(),
+                }
+            }
+            // 정책 메시지에 "비밀번호가 일치하지 않습니다" 등 로그인 실패와 같은 문구가 쓰이는 경우
+            USaintSessionError::WrongPassword(message) => match classify_policy_message(&message) {
+                Some(policy_error) => policy_error.into(),
+                None => USaintSessionError::WrongPassword(message),
+            },
+            error => error,
+        });
+    }
+
+    if is_login_page(&body) {
+        return Err(USaintSessionError::MissingMYSAPSSO2Cookie);
+    }
+
+    Ok(())
+}
+
+/// 비밀번호 변경 폼의 전송 주소와 숨은 입력 필드
+struct PasswordChangeForm {
+    action: Url,
+    hidden_fields: Vec<(String, String)>,
+}
+
+/// 기존 비밀번호로 로그인해 비밀번호가 만료되었을 때 내려오는 변경 폼을 가져옵니다.
+async fn fetch_password_change_form(
+    client: &Client,
+    config: &USaintConfig,
+    credentials: &Credentials,
+) -> Result<PasswordChangeForm, USaintSessionError> {
+    let form_data = [
+        ("sap-user", credentials.id()),
+        ("sap-password", credentials.password()),
+        ("sap-system-login", "onLogin"),
+    ];
+
+    let response = client
+        .post(&config.login_url)
+        .form(&form_data)
+        .send()
+        .await?;
+
+    let status = response.status();
+    let url = response.url().clone();
+    let body = response.text().await?;
+
+    match login::parse_login_failure(status, &body) {
+        Some(USaintSessionError::PasswordExpired(_)) => Ok(parse_password_change_form(&url, &body)),
+        Some(error) => Err(error),
+        None if is_login_page(&body) => Err(USaintSessionError::MissingMYSAPSSO2Cookie),
+        None => Err(USaintSessionError::PasswordNotExpired),
+    }
+}
+
+/// 새 비밀번호 입력 필드가 있는 폼(없으면 첫 번째 폼)의 전송 주소와 숨은 입력 필드를 읽습니다.
+/// 폼에 전송 주소가 없으면 폼을 받은 주소로 전송합니다.
+fn parse_password_change_form(url: &Url, body: &str) -> PasswordChangeForm {
+    let document = Html::parse_document(body);
+    let form_selector = Selector::parse(PASSWORD_CHANGE_FORM_SELECTOR).unwrap();
+    let new_password_selector = Selector::parse(NEW_PASSWORD_INPUT_SELECTOR).unwrap();
+    let hidden_selector = Selector::parse(HIDDEN_INPUT_SELECTOR).unwrap();
+
+    let form = document
+        .select(&form_selector)
+        .find(|form| form.select(&new_password_selector).next().is_some())
+        .or_else(|| document.select(&form_selector).next());
+
+    let Some(form) = form else {
+        return PasswordChangeForm {
+            action: url.clone(),
+            hidden_fields: Vec::new(),
+        };
+    };
+
+    let action = form
+        .value()
+        .attr("action")
+        .filter(|action| !action.trim().is_empty())
+        .and_then(|action| url.join(action.trim()).ok())
+        .unwrap_or_else(|| url.clone());
+    let hidden_fields = form
+        .select(&hidden_selector)
+        .filter_map(|input| {
+            let name = input.value().attr("name")?;
+            let value = input.value().attr("value").unwrap_or_default();
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect();
+
+    PasswordChangeForm {
+        action,
+        hidden_fields,
+    }
+}
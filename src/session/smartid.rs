@@ -0,0 +1,61 @@
+use regex::Regex;
+use reqwest::Client;
+
+use super::{credentials::Credentials, login, USaintSessionError};
+use crate::config::USaintConfig;
+
+const SMARTID_TOKEN_COOKIE_NAME: &str = "sToken";
+
+/// 스마트 ID 통합 로그인으로 토큰을 발급받은 뒤, 유세인트 포털에서 SAP 로그온 티켓(MYSAPSSO2)으로 교환합니다.
+pub(super) async fn fetch_sso_token(
+    client: &Client,
+    config: &USaintConfig,
+    credentials: &Credentials,
+) -> Result<(), USaintSessionError> {
+    let form_data = [
+        ("in_tp_bit", "0"),
+        ("rqst_caus_cd", "03"),
+        ("userid", credentials.id()),
+        ("pwd", credentials.password()),
+    ];
+
+    let response = client
+        .post(&config.smartid_login_url)
+        .form(&form_data)
+        .send()
+        .await?;
+
+    let token = response
+        .cookies()
+        .find(|cookie| cookie.name() == SMARTID_TOKEN_COOKIE_NAME)
+        .map(|cookie| cookie.value().to_string());
+
+    let Some(token) = token else {
+        // 로그인에 실패하면 스마트 ID는 토큰 대신 alert 스크립트로 메시지를 보여줌
+        let body = response.text().await?;
+        return Err(match find_alert_message(&body) {
+            Some(message) => login::classify_login_message(message),
+            None => USaintSessionError::MissingSmartIdToken,
+        });
+    };
+
+    // 포털이 MYSAPSSO2 쿠키를 발급하면서 쿠키 저장소에 저장됨
+    client
+        .get(&config.portal_sso_url)
+        .query(&[("sToken", token.as_str()), ("sIdno", credentials.id())])
+        .send()
+        .await?
+        .error_for_status()?;
+
+    Ok(())
+}
+
+/// 스마트 ID 응답의 `alert("...")` 메시지를 찾습니다.
+fn find_alert_message(body: &str) -> Option<String> {
+    let alert_pattern = Regex::new(r#"alert\(\s*["']([^"']*)["']\s*\)"#).unwrap();
+
+    alert_pattern
+        .captures(body)
+        .map(|caps| caps[1].trim().to_string())
+        .filter(|message| !message.is_empty())
+}
@@ -0,0 +1,103 @@
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+/// 세션 복원에 필요한 쿠키 정보를 담는 직렬화 가능한 스냅샷입니다.
+///
+/// MYSAPSSO2 로그온 티켓이 그대로 들어 있으므로 비밀번호와 같은 자격 증명으로 취급해야 합니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub cookies: Vec<StoredCookie>,
+}
+
+/// 특정 URL에 대해 저장된 쿠키 하나를 나타냅니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredCookie {
+    pub url: String,
+    pub name: String,
+    pub value: String,
+    /// `Domain` 속성. 없으면 `url`의 호스트에만 전송되는 쿠키입니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// `Path` 속성. 없으면 `/`로 복원합니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// `Secure` 속성
+    #[serde(default)]
+    pub secure: bool,
+}
+
+impl StoredCookie {
+    /// 주어진 URL에 대한 속성 없는 쿠키를 만듭니다.
+    pub fn new(url: &str, name: &str, value: &str) -> Self {
+        StoredCookie {
+            url: url.to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            domain: None,
+            path: None,
+            secure: false,
+        }
+    }
+
+    /// 저장된 속성을 담은 `Set-Cookie` 헤더 형식의 문자열을 반환합니다.
+    pub fn to_set_cookie(&self) -> String {
+        let mut set_cookie = format!(
+            "{}={}; Path={}",
+            self.name,
+            self.value,
+            self.path.as_deref().unwrap_or("/")
+        );
+        if let Some(domain) = &self.domain {
+            set_cookie.push_str(&format!("; Domain={}", domain));
+        }
+        if self.secure {
+            set_cookie.push_str("; Secure");
+        }
+        set_cookie
+    }
+
+    /// `Domain` 속성이 없는 쿠키는 URL의 호스트를 도메인으로 삼아 비교합니다.
+    fn is_same_entry(&self, other: &StoredCookie) -> bool {
+        let domain = |cookie: &StoredCookie| {
+            cookie.domain.clone().or_else(|| {
+                Url::parse(&cookie.url)
+                    .ok()
+                    .and_then(|url| url.host_str().map(str::to_string))
+            })
+        };
+        self.name == other.name && self.path == other.path && domain(self) == domain(other)
+    }
+}
+
+impl SessionSnapshot {
+    /// `Cookie` 헤더 형식(`name=value; name2=value2`)의 문자열에서 쿠키를 읽어 스냅샷에 추가합니다.
+    pub fn add_cookie_header(&mut self, url: &str, header: &str) {
+        for pair in header.split(';') {
+            if let Some((name, value)) = pair.trim().split_once('=') {
+                self.add_cookie(url, name, value);
+            }
+        }
+    }
+
+    /// 주어진 URL에 대한 쿠키 하나를 스냅샷에 추가합니다.
+    pub fn add_cookie(&mut self, url: &str, name: &str, value: &str) {
+        self.cookies.push(StoredCookie::new(url, name, value));
+    }
+
+    /// 속성이 있는 쿠키를 스냅샷에 추가합니다.
+    /// 쿠키 저장소에서 같은 항목에 해당하는 쿠키(이름, 도메인, 경로가 같은 쿠키)가 이미 있으면 추가하지 않습니다.
+    pub fn add_stored_cookie(&mut self, cookie: StoredCookie) {
+        if !self
+            .cookies
+            .iter()
+            .any(|stored| stored.is_same_entry(&cookie))
+        {
+            self.cookies.push(cookie);
+        }
+    }
+
+    /// 스냅샷에 주어진 이름의 쿠키가 있는지 확인합니다.
+    pub fn contains(&self, name: &str) -> bool {
+        self.cookies.iter().any(|cookie| cookie.name == name)
+    }
+}
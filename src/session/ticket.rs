@@ -0,0 +1,280 @@
+use std::str::FromStr;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use thiserror::Error;
+
+/// 티켓 필드 식별자
+const FIELD_USER: u8 = 0x01;
+const FIELD_CLIENT: u8 = 0x02;
+const FIELD_SYSTEM_ID: u8 = 0x03;
+const FIELD_CREATED_AT: u8 = 0x04;
+const FIELD_VALID_HOURS: u8 = 0x05;
+const FIELD_VALID_MINUTES: u8 = 0x07;
+const FIELD_PORTAL_USER: u8 = 0x0A;
+const FIELD_AUTH_SCHEME: u8 = 0x0B;
+const FIELD_SIGNATURE: u8 = 0xFF;
+
+/// 정수 필드의 최대 길이 (바이트)
+const MAX_INTEGER_LENGTH: usize = 8;
+
+/// 생성 시각 필드의 형식 (UTC, `YYYYMMDDHHMM`)
+const CREATED_AT_FORMAT: &str = "%Y%m%d%H%M";
+
+#[derive(Debug, Error)]
+pub enum LogonTicketError {
+    #[error("티켓이 올바른 base64 형식이 아닙니다.")]
+    InvalidEncoding,
+    #[error("티켓 데이터가 잘렸습니다.")]
+    Truncated,
+    #[error("지원하지 않는 코드 페이지입니다: {0}")]
+    UnsupportedCodepage(String),
+    #[error("티켓에 {0} 필드가 없습니다.")]
+    MissingField(&'static str),
+    #[error("티켓 생성 시각을 해석할 수 없습니다: {0}")]
+    InvalidCreationTime(String),
+    #[error("티켓의 {field} 필드가 {length}바이트로, 정수 필드의 최대 길이를 넘습니다.")]
+    IntegerTooLong { field: &'static str, length: usize },
+    #[error("티켓의 유효 기간이 표현할 수 있는 범위를 벗어났습니다.")]
+    InvalidValidity,
+}
+
+/// MYSAPSSO2 쿠키에 담긴 SAP 로그온 티켓
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogonTicket {
+    version: u8,
+    codepage: String,
+    user: String,
+    client: Option<String>,
+    system_id: Option<String>,
+    created_at: DateTime<Utc>,
+    validity: Duration,
+    expires_at: DateTime<Utc>,
+    portal_user: Option<String>,
+    auth_scheme: Option<String>,
+    signed: bool,
+}
+
+impl LogonTicket {
+    /// 티켓 형식 버전
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// 티켓의 문자열 필드에 사용된 SAP 코드 페이지 (예: `4103`은 UTF-16LE)
+    pub fn codepage(&self) -> &str {
+        &self.codepage
+    }
+
+    /// 티켓이 발급된 사용자 ID (학번)
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// 티켓을 발급한 SAP 클라이언트 번호
+    pub fn client(&self) -> Option<&str> {
+        self.client.as_deref()
+    }
+
+    /// 티켓을 발급한 SAP 시스템 ID
+    pub fn system_id(&self) -> Option<&str> {
+        self.system_id.as_deref()
+    }
+
+    /// 포털 사용자 ID
+    pub fn portal_user(&self) -> Option<&str> {
+        self.portal_user.as_deref()
+    }
+
+    /// 로그인에 사용된 인증 방식
+    pub fn auth_scheme(&self) -> Option<&str> {
+        self.auth_scheme.as_deref()
+    }
+
+    /// 티켓에 디지털 서명이 포함되어 있는지 여부 (서명 자체는 검증하지 않습니다)
+    pub fn is_signed(&self) -> bool {
+        self.signed
+    }
+
+    /// 티켓 생성 시각
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// 티켓의 유효 기간
+    pub fn validity(&self) -> Duration {
+        self.validity
+    }
+
+    /// 티켓 만료 시각
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    /// 현재 시각을 기준으로 티켓이 만료되었는지 확인합니다.
+    pub fn is_expired(&self) -> bool {
+        Utc::now() >= self.expires_at()
+    }
+
+    /// 티켓 만료까지 남은 시간을 반환합니다. 이미 만료되었으면 0을 반환합니다.
+    pub fn remaining(&self) -> Duration {
+        (self.expires_at() - Utc::now()).max(Duration::zero())
+    }
+
+    /// base64로 디코딩된 티켓 바이트를 해석합니다.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, LogonTicketError> {
+        let (&version, rest) = bytes.split_first().ok_or(LogonTicketError::Truncated)?;
+        if rest.len() < 4 {
+            return Err(LogonTicketError::Truncated);
+        }
+        let (codepage, mut rest) = rest.split_at(4);
+        let codepage = String::from_utf8_lossy(codepage).to_string();
+
+        let mut user = None;
+        let mut client = None;
+        let mut system_id = None;
+        let mut created_at = None;
+        let mut valid_hours = 0;
+        let mut valid_minutes = 0;
+        let mut portal_user = None;
+        let mut auth_scheme = None;
+        let mut signed = false;
+
+        // 각 필드는 (식별자 1바이트, 길이 2바이트(big-endian), 값) 형식으로 이어집니다.
+        while !rest.is_empty() {
+            if rest.len() < 3 {
+                return Err(LogonTicketError::Truncated);
+            }
+            let id = rest[0];
+            let length = u16::from_be_bytes([rest[1], rest[2]]) as usize;
+            if rest.len() < 3 + length {
+                return Err(LogonTicketError::Truncated);
+            }
+            let value = &rest[3..3 + length];
+            rest = &rest[3 + length..];
+
+            match id {
+                FIELD_USER => user = Some(decode_string(&codepage, value)?),
+                FIELD_CLIENT => client = Some(decode_string(&codepage, value)?),
+                FIELD_SYSTEM_ID => system_id = Some(decode_string(&codepage, value)?),
+                FIELD_CREATED_AT => created_at = Some(decode_string(&codepage, value)?),
+                FIELD_VALID_HOURS => valid_hours = decode_integer("유효 시간", value)?,
+                FIELD_VALID_MINUTES => valid_minutes = decode_integer("유효 분", value)?,
+                FIELD_PORTAL_USER => portal_user = Some(decode_string(&codepage, value)?),
+                FIELD_AUTH_SCHEME => auth_scheme = Some(decode_string(&codepage, value)?),
+                FIELD_SIGNATURE => signed = true,
+                _ => {}
+            }
+        }
+
+        let created_at = created_at.ok_or(LogonTicketError::MissingField("생성 시각"))?;
+        let created_at = NaiveDateTime::parse_from_str(created_at.trim(), CREATED_AT_FORMAT)
+            .map_err(|_| LogonTicketError::InvalidCreationTime(created_at.clone()))?
+            .and_utc();
+
+        // 쿠키에서 온 값이므로 기간과 만료 시각이 표현 범위를 넘으면 오류로 처리
+        let hours = i64::try_from(valid_hours)
+            .ok()
+            .and_then(Duration::try_hours);
+        let minutes = i64::try_from(valid_minutes)
+            .ok()
+            .and_then(Duration::try_minutes);
+        let validity = match (hours, minutes) {
+            (Some(hours), Some(minutes)) => hours.checked_add(&minutes),
+            _ => None,
+        }
+        .ok_or(LogonTicketError::InvalidValidity)?;
+        let expires_at = created_at
+            .checked_add_signed(validity)
+            .ok_or(LogonTicketError::InvalidValidity)?;
+
+        Ok(LogonTicket {
+            version,
+            codepage,
+            user: user
+                .ok_or(LogonTicketError::MissingField("사용자"))?
+                .trim()
+                .to_string(),
+            client,
+            system_id,
+            created_at,
+            validity,
+            expires_at,
+            portal_user,
+            auth_scheme,
+            signed,
+        })
+    }
+}
+
+impl FromStr for LogonTicket {
+    type Err = LogonTicketError;
+
+    /// 쿠키 값 그대로(URL 인코딩된 base64)의 MYSAPSSO2 티켓을 해석합니다.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let decoded = percent_decode(value.trim());
+        let bytes = BASE64
+            .decode(decoded.as_bytes())
+            .map_err(|_| LogonTicketError::InvalidEncoding)?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// 코드 페이지에 맞게 문자열 필드를 디코딩합니다.
+fn decode_string(codepage: &str, value: &[u8]) -> Result<String, LogonTicketError> {
+    match codepage {
+        // UTF-16BE
+        "4102" => Ok(decode_utf16(value, u16::from_be_bytes)),
+        // UTF-16LE
+        "4103" => Ok(decode_utf16(value, u16::from_le_bytes)),
+        // UTF-8
+        "4110" => Ok(String::from_utf8_lossy(value).to_string()),
+        // ISO-8859-1
+        "1100" | "0000" => Ok(value.iter().map(|&byte| byte as char).collect()),
+        _ => Err(LogonTicketError::UnsupportedCodepage(codepage.to_string())),
+    }
+}
+
+fn decode_utf16(value: &[u8], from_bytes: fn([u8; 2]) -> u16) -> String {
+    let units: Vec<u16> = value
+        .chunks_exact(2)
+        .map(|chunk| from_bytes([chunk[0], chunk[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// big-endian 부호 없는 정수 필드를 디코딩합니다. 8바이트보다 긴 필드는 오류를 반환합니다.
+fn decode_integer(field: &'static str, value: &[u8]) -> Result<u64, LogonTicketError> {
+    if value.len() > MAX_INTEGER_LENGTH {
+        return Err(LogonTicketError::IntegerTooLong {
+            field,
+            length: value.len(),
+        });
+    }
+    Ok(value
+        .iter()
+        .fold(0u64, |acc, &byte| (acc << 8) | byte as u64))
+}
+
+/// 쿠키 값의 `%XX` 형식 문자를 디코딩합니다.
+fn percent_decode(value: &str) -> String {
+    let mut decoded = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '%' {
+            let hex: String = chars.by_ref().take(2).collect();
+            match u8::from_str_radix(&hex, 16) {
+                Ok(byte) => decoded.push(byte as char),
+                Err(_) => {
+                    decoded.push('%');
+                    decoded.push_str(&hex);
+                }
+            }
+        } else {
+            decoded.push(ch);
+        }
+    }
+
+    decoded
+}
@@ -1,2 +1,7 @@
 pub mod client;
+pub mod document;
+pub mod element;
 pub mod event;
+pub mod lsdata;
+pub mod page;
+pub mod response;
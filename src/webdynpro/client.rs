@@ -1,13 +1,51 @@
 use std::sync::Arc;
 
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use scraper::{Html, Selector};
 use thiserror::Error;
 
+use super::page;
+use crate::config::{self, USaintConfig};
+
 #[derive(Debug, Error)]
 pub enum SapSsrClientError {
     #[error("HTTP 요청 오류: {0}")]
     RequestError(#[from] reqwest::Error),
+    #[error("SSO 세션이 만료되어 로그인 페이지로 이동되었습니다.")]
+    SessionExpired { snippet: String },
+    #[error("{app_name} 애플리케이션에 접근할 권한이 없습니다.")]
+    AuthorizationDenied { app_name: String, snippet: String },
+    #[error("{app_name} 애플리케이션을 찾을 수 없습니다.")]
+    ApplicationNotFound { app_name: String, snippet: String },
+    #[error("{app_name} 애플리케이션 요청이 HTTP {status} 상태로 실패했습니다.")]
+    HttpStatus {
+        app_name: String,
+        status: StatusCode,
+        snippet: String,
+    },
+    #[error("{app_name} 애플리케이션 페이지에 SSR 폼이 없습니다.")]
+    MissingSsrForm { app_name: String, snippet: String },
+    #[error("{app_name} 애플리케이션 페이지에 SSR 폼의 {field} 값이 없습니다.")]
+    MissingFormField {
+        app_name: String,
+        field: &'static str,
+        snippet: String,
+    },
+}
+
+impl SapSsrClientError {
+    /// 오류를 일으킨 페이지의 앞부분을 반환합니다.
+    pub fn snippet(&self) -> Option<&str> {
+        match self {
+            SapSsrClientError::RequestError(_) => None,
+            SapSsrClientError::SessionExpired { snippet }
+            | SapSsrClientError::AuthorizationDenied { snippet, .. }
+            | SapSsrClientError::ApplicationNotFound { snippet, .. }
+            | SapSsrClientError::HttpStatus { snippet, .. }
+            | SapSsrClientError::MissingSsrForm { snippet, .. }
+            | SapSsrClientError::MissingFormField { snippet, .. } => Some(snippet),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -17,22 +55,22 @@ pub struct SapSsrClient {
     pub wd_secure_id: String,
     pub app_name: String,
     pub use_beacon: bool,
+    /// SSR 폼이 포함된 첫 페이지
+    pub page: String,
 }
 
 impl SapSsrClient {
-    pub const SSU_WEBDYNPRO_BASE_URL: &'static str = "https://ecc.ssu.ac.kr";
+    pub const SSU_WEBDYNPRO_BASE_URL: &'static str = config::SSU_WEBDYNPRO_BASE_URL;
 
     pub async fn new(
         client: Arc<Client>,
+        config: &USaintConfig,
         app_name: &str,
     ) -> Result<SapSsrClient, SapSsrClientError> {
-        let url = format!(
-            "{}/sap/bc/webdynpro/SAP/{}?sap-wd-stableids=x",
-            Self::SSU_WEBDYNPRO_BASE_URL,
-            app_name
-        );
+        let url = config.application_url(app_name);
 
         let response = client.get(url).send().await?;
+        let status = response.status();
         let body = response.text().await?;
 
         // HTML 문자열 파싱
@@ -41,39 +79,81 @@ impl SapSsrClient {
         let form_selector = Selector::parse("#sap\\.client\\.SsrClient\\.form").unwrap();
         let input_selector = Selector::parse("input").unwrap();
 
-        let mut action_url = String::new();
-        let mut charset = String::new();
-        let mut wd_secure_id = String::new();
-        let mut app_name = String::new();
+        let Some(form_element) = document.select(&form_selector).next() else {
+            return Err(Self::classify_page(app_name, status, &body));
+        };
+
+        let action_url = form_element.value().attr("action").map(str::to_string);
+        let mut charset = None;
+        let mut wd_secure_id = None;
+        let mut fesr_app_name = None;
         let mut use_beacon = false;
 
-        for form_element in document.select(&form_selector) {
-            action_url = form_element
-                .value()
-                .attr("action")
-                .unwrap_or("")
-                .to_string();
-
-            for input_element in form_element.select(&input_selector) {
-                let name = input_element.value().attr("name").unwrap_or("");
-                let value = input_element.value().attr("value").unwrap_or("");
-
-                match name {
-                    "sap-charset" => charset = value.to_string(),
-                    "sap-wd-secure-id" => wd_secure_id = value.to_string(),
-                    "fesrAppName" => app_name = value.to_string(),
-                    "fesrUseBeacon" => use_beacon = value == "true",
-                    _ => {}
-                }
+        for input_element in form_element.select(&input_selector) {
+            let name = input_element.value().attr("name").unwrap_or("");
+            let value = input_element.value().attr("value").unwrap_or("");
+
+            match name {
+                "sap-charset" => charset = Some(value.to_string()),
+                "sap-wd-secure-id" => wd_secure_id = Some(value.to_string()),
+                "fesrAppName" => fesr_app_name = Some(value.to_string()),
+                "fesrUseBeacon" => use_beacon = value == "true",
+                _ => {}
             }
         }
 
+        // 세션 만료 안내가 SSR 폼과 함께 내려오는 경우
+        if page::is_session_expired(&body) {
+            return Err(SapSsrClientError::SessionExpired {
+                snippet: page::snippet(&body),
+            });
+        }
+
+        let required = |field: &'static str, value: Option<String>| {
+            value.filter(|value| !value.is_empty()).ok_or_else(|| {
+                SapSsrClientError::MissingFormField {
+                    app_name: app_name.to_string(),
+                    field,
+                    snippet: page::snippet(&body),
+                }
+            })
+        };
+
+        let action_url = required("action", action_url)?;
+        let charset = required("sap-charset", charset)?;
+        let wd_secure_id = required("sap-wd-secure-id", wd_secure_id)?;
+        let app_name = required("fesrAppName", fesr_app_name)?;
+
         Ok(SapSsrClient {
             action_url,
             charset,
             wd_secure_id,
             app_name,
             use_beacon,
+            page: body,
         })
     }
+
+    /// SSR 폼이 없는 페이지를 보고 실패 원인을 분류합니다.
+    fn classify_page(app_name: &str, status: StatusCode, body: &str) -> SapSsrClientError {
+        let app_name = app_name.to_string();
+        let snippet = page::snippet(body);
+
+        // 로그인 페이지는 401 등 오류 상태와 함께 내려오기도 하므로 가장 먼저 확인
+        if page::is_session_expired(body) {
+            SapSsrClientError::SessionExpired { snippet }
+        } else if status == StatusCode::FORBIDDEN || page::is_authorization_denied(body) {
+            SapSsrClientError::AuthorizationDenied { app_name, snippet }
+        } else if status == StatusCode::NOT_FOUND || page::is_application_not_found(body) {
+            SapSsrClientError::ApplicationNotFound { app_name, snippet }
+        } else if !status.is_success() {
+            SapSsrClientError::HttpStatus {
+                app_name,
+                status,
+                snippet,
+            }
+        } else {
+            SapSsrClientError::MissingSsrForm { app_name, snippet }
+        }
+    }
 }
@@ -0,0 +1,213 @@
+use ego_tree::{NodeId, NodeRef, Tree};
+use scraper::{ElementRef, Html, Node};
+use thiserror::Error;
+
+use super::{
+    element::id_selector,
+    response::{Update, WebDynproResponse},
+};
+
+#[derive(Debug, Error)]
+pub enum DocumentError {
+    #[error("업데이트할 {0} 요소가 문서에 없습니다.")]
+    MissingTarget(String),
+}
+
+/// 브라우저의 SSR 클라이언트처럼 서버 응답을 누적해 현재 화면 상태를 유지하는 WebDynpro 문서
+///
+/// 처음 불러온 페이지에서 시작해, 이후의 델타 응답을 컨트롤 ID 기준으로 적용합니다.
+/// 전체 페이지 응답을 받으면 문서 전체를 교체합니다.
+/// 파싱된 트리를 그대로 유지하므로 질의할 때마다 페이지를 다시 파싱하지 않습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebDynproDocument {
+    document: Html,
+}
+
+impl Default for WebDynproDocument {
+    fn default() -> Self {
+        WebDynproDocument {
+            document: Html::new_document(),
+        }
+    }
+}
+
+impl WebDynproDocument {
+    /// 주어진 페이지로 문서를 시작합니다.
+    pub fn new(page: impl AsRef<str>) -> Self {
+        WebDynproDocument {
+            document: Html::parse_document(page.as_ref()),
+        }
+    }
+
+    /// 현재 문서를 HTML로 직렬화합니다.
+    pub fn html(&self) -> String {
+        self.document.html()
+    }
+
+    /// 현재 문서의 파싱된 트리를 반환합니다.
+    pub fn parsed(&self) -> &Html {
+        &self.document
+    }
+
+    /// 주어진 ID의 컨트롤이 문서에 있는지 확인합니다.
+    pub fn contains(&self, id: &str) -> bool {
+        find_element(&self.document, id).is_some()
+    }
+
+    /// 주어진 ID를 가진 컨트롤의 현재 HTML을 반환합니다.
+    pub fn control_html(&self, id: &str) -> Option<String> {
+        let node = self.document.tree.get(find_element(&self.document, id)?)?;
+        ElementRef::wrap(node).map(|element| element.html())
+    }
+
+    /// 서버 응답을 문서에 적용합니다.
+    /// 업데이트할 요소가 문서에 없으면 오류를 반환하며, 이때 문서는 바뀌지 않습니다.
+    pub fn apply(&mut self, response: &WebDynproResponse) -> Result<(), DocumentError> {
+        let updates = match response {
+            WebDynproResponse::Page(page) => {
+                self.document = Html::parse_document(page);
+                return Ok(());
+            }
+            WebDynproResponse::Updates(updates) => updates,
+        };
+
+        // 중간에 실패하면 일부만 적용된 문서가 남지 않도록 사본에 적용한 뒤 교체
+        let mut document = self.document.clone();
+        for update in updates {
+            match update {
+                Update::Content { id, html, .. } => replace_content(&mut document, id, html)?,
+                Update::Control { id, html, .. } => replace_control(&mut document, id, html)?,
+                _ => {}
+            }
+        }
+        self.document = document;
+
+        Ok(())
+    }
+}
+
+/// 주어진 ID 요소의 내용을 새 HTML로 교체합니다.
+fn replace_content(document: &mut Html, id: &str, html: &str) -> Result<(), DocumentError> {
+    let target =
+        find_element(document, id).ok_or_else(|| DocumentError::MissingTarget(id.to_string()))?;
+
+    let children: Vec<NodeId> = document
+        .tree
+        .get(target)
+        .unwrap()
+        .children()
+        .map(|child| child.id())
+        .collect();
+    for child in children {
+        document.tree.get_mut(child).unwrap().detach();
+    }
+
+    // 대상 요소 안에서 파싱해야 테이블 행과 셀이 보존됨
+    let context = element_name(document, target);
+    let (fragment, container) = parse_fragment_in(&context, html);
+    for node in fragment.tree.get(container).unwrap().children() {
+        append_copy(&mut document.tree, target, node);
+    }
+
+    Ok(())
+}
+
+/// 주어진 ID의 컨트롤을 새 HTML로 교체합니다.
+fn replace_control(document: &mut Html, id: &str, html: &str) -> Result<(), DocumentError> {
+    let target =
+        find_element(document, id).ok_or_else(|| DocumentError::MissingTarget(id.to_string()))?;
+
+    // 교체될 요소의 부모 안에서 파싱해야 `<tr>` 같은 컨트롤이 보존됨
+    let context = document
+        .tree
+        .get(target)
+        .unwrap()
+        .parent()
+        .map(|parent| element_name(document, parent.id()))
+        .unwrap_or_default();
+    let (fragment, container) = parse_fragment_in(&context, html);
+    for node in fragment.tree.get(container).unwrap().children() {
+        let copied = document
+            .tree
+            .get_mut(target)
+            .unwrap()
+            .insert_before(node.value().clone())
+            .id();
+        for child in node.children() {
+            append_copy(&mut document.tree, copied, child);
+        }
+    }
+    document.tree.get_mut(target).unwrap().detach();
+
+    Ok(())
+}
+
+/// 주어진 요소 안에 들어가는 HTML 조각을 브라우저처럼 요소의 문맥에서 파싱합니다.
+/// 테이블 요소는 `<body>` 문맥에서 파싱하면 `<tr>`, `<td>` 태그가 버려지므로, 같은 테이블 구조로
+/// 감싸서 파싱한 뒤 감싼 요소를 조각의 부모로 반환합니다.
+fn parse_fragment_in(context: &str, html: &str) -> (Html, NodeId) {
+    let path: &[&str] = match context {
+        "table" => &["table"],
+        "thead" => &["table", "thead"],
+        "tbody" => &["table", "tbody"],
+        "tfoot" => &["table", "tfoot"],
+        "tr" => &["table", "tbody", "tr"],
+        _ => &[],
+    };
+
+    let wrapped = path
+        .iter()
+        .map(|name| format!("<{}>", name))
+        .chain(std::iter::once(html.to_string()))
+        .chain(path.iter().rev().map(|name| format!("</{}>", name)))
+        .collect::<String>();
+    let fragment = Html::parse_fragment(&wrapped);
+
+    let mut container = fragment.root_element();
+    for name in path {
+        let Some(child) = container
+            .children()
+            .filter_map(ElementRef::wrap)
+            .find(|child| child.value().name() == *name)
+        else {
+            break;
+        };
+        container = child;
+    }
+    let container = container.id();
+
+    (fragment, container)
+}
+
+fn element_name(document: &Html, id: NodeId) -> String {
+    document
+        .tree
+        .get(id)
+        .and_then(|node| {
+            node.value()
+                .as_element()
+                .map(|element| element.name().to_string())
+        })
+        .unwrap_or_default()
+}
+
+/// 다른 트리의 노드를 하위 노드까지 모두 복사해 `parent`의 마지막 자식으로 추가합니다.
+fn append_copy(tree: &mut Tree<Node>, parent: NodeId, source: NodeRef<Node>) {
+    let copied = tree
+        .get_mut(parent)
+        .unwrap()
+        .append(source.value().clone())
+        .id();
+    for child in source.children() {
+        append_copy(tree, copied, child);
+    }
+}
+
+fn find_element(document: &Html, id: &str) -> Option<NodeId> {
+    let selector = id_selector(id)?;
+    let element = document
+        .select(&selector)
+        .next()
+        .map(|element| element.id());
+    element
+}
@@ -0,0 +1,232 @@
+use scraper::{ElementRef, Html, Selector};
+use thiserror::Error;
+
+use super::{
+    event::{SapEvent, SapEventParameters},
+    lsdata::{LsData, LsDataError, LsEvents},
+};
+
+pub mod button;
+pub mod check_box;
+pub mod combo_box;
+pub mod input_field;
+pub mod link;
+pub mod popup_window;
+pub mod radio_button;
+pub mod sap_table;
+pub mod tab_strip;
+pub mod text_view;
+pub mod tray;
+
+/// 서버에 바로 전송되어 화면 변경분을 응답받는 이벤트의 ucf 파라미터
+const SUBMIT_UCF_PARAMETERS: [(&str, &str); 2] =
+    [("ResponseData", "delta"), ("ClientAction", "submit")];
+/// 다음 submit 이벤트와 함께 전송되도록 대기열에 쌓이는 이벤트의 ucf 파라미터
+const ENQUEUE_UCF_PARAMETERS: [(&str, &str); 2] = [
+    ("ClientAction", "enqueue"),
+    ("EnqueueCardinality", "single"),
+];
+
+#[derive(Debug, Error)]
+pub enum ElementError {
+    #[error("{0} 컨트롤을 찾을 수 없습니다.")]
+    NotFound(String),
+    #[error("{id} 컨트롤은 {expected}이(가) 아닙니다. (ct={found})")]
+    UnexpectedControl {
+        id: String,
+        expected: &'static str,
+        found: String,
+    },
+    #[error("{id} 컨트롤에 {attribute} 속성이 없습니다.")]
+    MissingAttribute { id: String, attribute: &'static str },
+    #[error("{id} 컨트롤의 {attribute} 속성을 해석할 수 없습니다: {error}")]
+    InvalidAttribute {
+        id: String,
+        attribute: &'static str,
+        error: LsDataError,
+    },
+    #[error("{id} 테이블에 {column} 열이 없습니다.")]
+    MissingColumn { id: String, column: String },
+    #[error("{id} 테이블의 {column} 열 값을 해석할 수 없습니다: {value}")]
+    InvalidCell {
+        id: String,
+        column: String,
+        value: String,
+    },
+}
+
+/// 모든 컨트롤이 공통으로 가지는 ID와 `lsdata`, `lsevents` 속성
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Control {
+    id: String,
+    lsdata: LsData,
+    lsevents: LsEvents,
+}
+
+impl Control {
+    /// 요소의 `ct` 속성이 주어진 컨트롤 종류인지 확인하고 공통 속성을 읽습니다.
+    fn from_element<T: Element>(element: ElementRef) -> Result<Self, ElementError> {
+        let id = element.value().attr("id").unwrap_or_default().to_string();
+        let control_type = element.value().attr("ct").unwrap_or_default();
+
+        if control_type != T::CONTROL_TYPE {
+            return Err(ElementError::UnexpectedControl {
+                id,
+                expected: T::NAME,
+                found: control_type.to_string(),
+            });
+        }
+        if id.is_empty() {
+            return Err(ElementError::MissingAttribute {
+                id,
+                attribute: "id",
+            });
+        }
+
+        let invalid_attribute = |attribute| {
+            let id = id.clone();
+            move |error| ElementError::InvalidAttribute {
+                id,
+                attribute,
+                error,
+            }
+        };
+        let lsdata = match element.value().attr("lsdata") {
+            Some(lsdata) => LsData::parse(lsdata).map_err(invalid_attribute("lsdata"))?,
+            None => LsData::default(),
+        };
+        let lsevents = match element.value().attr("lsevents") {
+            Some(lsevents) => LsEvents::parse(lsevents).map_err(invalid_attribute("lsevents"))?,
+            None => LsEvents::default(),
+        };
+
+        Ok(Control {
+            id,
+            lsdata,
+            lsevents,
+        })
+    }
+
+    /// 컨트롤 ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// 컨트롤의 현재 상태를 담은 `lsdata` 속성
+    pub fn lsdata(&self) -> &LsData {
+        &self.lsdata
+    }
+
+    /// 컨트롤이 받는 이벤트를 담은 `lsevents` 속성
+    pub fn lsevents(&self) -> &LsEvents {
+        &self.lsevents
+    }
+}
+
+/// WebDynpro 컨트롤 래퍼의 공통 동작
+pub trait Element: Sized {
+    /// 컨트롤 HTML 요소의 `ct` 속성 값
+    const CONTROL_TYPE: &'static str;
+    /// 이벤트 이름에 쓰이는 컨트롤 이름 (예: `Button_Press`의 `Button`)
+    const NAME: &'static str;
+
+    /// 컨트롤의 HTML 요소로부터 래퍼를 생성합니다.
+    fn from_element(element: ElementRef) -> Result<Self, ElementError>;
+
+    /// 컨트롤의 공통 속성
+    fn control(&self) -> &Control;
+
+    /// 컨트롤 ID
+    fn id(&self) -> &str {
+        self.control().id()
+    }
+
+    /// 파싱된 페이지에서 주어진 ID의 컨트롤을 찾습니다.
+    fn find(document: &Html, id: &str) -> Result<Self, ElementError> {
+        let element = id_selector(id)
+            .and_then(|selector| document.select(&selector).next())
+            .ok_or_else(|| ElementError::NotFound(id.to_string()))?;
+        Self::from_element(element)
+    }
+}
+
+/// 주어진 ID를 가진 요소의 선택자(`[id="..."]`)를 만듭니다.
+/// ID는 서버가 보낸 값이므로 CSS 문자열로 escape하며, 그래도 선택자를 만들 수 없으면 `None`을 반환합니다.
+pub(crate) fn id_selector(id: &str) -> Option<Selector> {
+    let mut escaped = String::with_capacity(id.len());
+    for ch in id.chars() {
+        match ch {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            // 줄바꿈 등 제어 문자는 문자열 안에 그대로 쓸 수 없으므로 16진수 escape로 씀
+            _ if ch.is_control() => escaped.push_str(&format!("\\{:x} ", ch as u32)),
+            _ => escaped.push(ch),
+        }
+    }
+
+    Selector::parse(&format!(r#"[id="{}"]"#, escaped)).ok()
+}
+
+/// 요소 안의 텍스트를 공백을 정리해 반환합니다.
+fn element_text(element: ElementRef) -> String {
+    element
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 화면 변경분을 바로 응답받는 컨트롤 이벤트를 만듭니다.
+fn submit_event<T: Element>(
+    control: &Control,
+    action: &str,
+    parameters: Vec<(&str, String)>,
+) -> SapEvent {
+    event::<T>(control, action, parameters, &SUBMIT_UCF_PARAMETERS)
+}
+
+/// 다음 submit 이벤트와 함께 전송되는 컨트롤 이벤트를 만듭니다.
+fn enqueue_event<T: Element>(
+    control: &Control,
+    action: &str,
+    parameters: Vec<(&str, String)>,
+) -> SapEvent {
+    event::<T>(control, action, parameters, &ENQUEUE_UCF_PARAMETERS)
+}
+
+/// 컨트롤의 `lsevents`에 이벤트 정의가 있으면 브라우저처럼 그 파라미터를 사용하고,
+/// 없으면 주어진 기본 ucf 파라미터를 사용합니다.
+fn event<T: Element>(
+    control: &Control,
+    action: &str,
+    parameters: Vec<(&str, String)>,
+    default_ucf_parameters: &[(&str, &str)],
+) -> SapEvent {
+    let (ucf_parameters, custom_parameters) = match control.lsevents.get(action) {
+        Some(definition) => (
+            definition.ucf_parameters.iter().cloned().collect(),
+            definition.custom_parameters.iter().cloned().collect(),
+        ),
+        None => (
+            default_ucf_parameters
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            SapEventParameters::new(),
+        ),
+    };
+
+    SapEvent {
+        event: T::NAME.to_string(),
+        control: action.to_string(),
+        parameters: parameters
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+        ucf_parameters,
+        custom_parameters,
+    }
+}
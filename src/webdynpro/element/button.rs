@@ -0,0 +1,58 @@
+use scraper::ElementRef;
+
+use super::{element_text, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// `lsdata`의 버튼 텍스트, 활성화 여부 키
+const LSDATA_TEXT: &str = "0";
+const LSDATA_ENABLED: &str = "2";
+
+/// 버튼 (`ct="B"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Button {
+    control: Control,
+    text: String,
+    enabled: bool,
+}
+
+impl Element for Button {
+    const CONTROL_TYPE: &'static str = "B";
+    const NAME: &'static str = "Button";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        let control = Control::from_element::<Self>(element)?;
+        let lsdata = control.lsdata();
+
+        Ok(Button {
+            text: lsdata
+                .get_str(LSDATA_TEXT)
+                .map(str::to_string)
+                .unwrap_or_else(|| element_text(element)),
+            enabled: lsdata
+                .get_bool(LSDATA_ENABLED)
+                .unwrap_or_else(|| element.value().attr("aria-disabled") != Some("true")),
+            control,
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl Button {
+    /// 버튼에 표시된 텍스트
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 버튼을 누를 수 있는지 여부
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 버튼을 누르는 이벤트를 만듭니다.
+    pub fn press(&self) -> SapEvent {
+        submit_event::<Self>(&self.control, "Press", vec![("Id", self.id().to_string())])
+    }
+}
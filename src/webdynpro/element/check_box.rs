@@ -0,0 +1,53 @@
+use scraper::ElementRef;
+
+use super::{element_text, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 체크 박스 (`ct="C"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckBox {
+    control: Control,
+    text: String,
+    checked: bool,
+}
+
+impl Element for CheckBox {
+    const CONTROL_TYPE: &'static str = "C";
+    const NAME: &'static str = "CheckBox";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(CheckBox {
+            control: Control::from_element::<Self>(element)?,
+            text: element_text(element),
+            checked: element.value().attr("aria-checked") == Some("true"),
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl CheckBox {
+    /// 체크 박스의 레이블
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 체크 여부
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// 체크 상태를 주어진 값으로 바꾸는 이벤트를 만듭니다.
+    pub fn toggle(&self, checked: bool) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "Toggle",
+            vec![
+                ("Id", self.id().to_string()),
+                ("Checked", checked.to_string()),
+            ],
+        )
+    }
+}
@@ -0,0 +1,139 @@
+use scraper::ElementRef;
+
+use super::{element_text, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// `lsdata`의 항목 목록(ListBox) ID, 선택된 키, 표시 값 키
+const LSDATA_ITEM_LIST_BOX_ID: &str = "2";
+const LSDATA_KEY: &str = "3";
+const LSDATA_VALUE: &str = "4";
+
+/// 콤보 박스의 선택 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComboBoxItem {
+    /// 항목을 선택할 때 서버에 보내는 키
+    pub key: String,
+    /// 화면에 표시되는 텍스트
+    pub text: String,
+}
+
+/// 콤보 박스 (`ct="CB"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComboBox {
+    control: Control,
+    key: Option<String>,
+    value: String,
+    item_list_box_id: Option<String>,
+    items: Vec<ComboBoxItem>,
+    read_only: bool,
+}
+
+impl Element for ComboBox {
+    const CONTROL_TYPE: &'static str = "CB";
+    const NAME: &'static str = "ComboBox";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        let control = Control::from_element::<Self>(element)?;
+        let lsdata = control.lsdata();
+
+        let item_list_box_id = lsdata.get_str(LSDATA_ITEM_LIST_BOX_ID).map(str::to_string);
+        let items = item_list_box_id
+            .as_deref()
+            .map(|id| list_box_items(element, id))
+            .unwrap_or_default();
+
+        Ok(ComboBox {
+            key: lsdata.get_str(LSDATA_KEY).map(str::to_string),
+            value: lsdata
+                .get_str(LSDATA_VALUE)
+                .or(element.value().attr("value"))
+                .unwrap_or_default()
+                .to_string(),
+            item_list_box_id,
+            items,
+            read_only: element.value().attr("readonly").is_some(),
+            control,
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl ComboBox {
+    /// 현재 선택된 항목의 키
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// 선택 가능한 항목이 담긴 ListBox 컨트롤의 ID
+    pub fn item_list_box_id(&self) -> Option<&str> {
+        self.item_list_box_id.as_deref()
+    }
+
+    /// 페이지에 있는 ListBox에서 읽은 선택 가능한 항목 목록
+    /// ListBox가 페이지에 없으면 빈 목록을 반환합니다.
+    pub fn items(&self) -> &[ComboBoxItem] {
+        &self.items
+    }
+
+    /// 주어진 키의 항목을 반환합니다.
+    pub fn item(&self, key: &str) -> Option<&ComboBoxItem> {
+        self.items.iter().find(|item| item.key == key)
+    }
+
+    /// 읽기 전용 여부
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// 현재 선택된 항목의 표시 값
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// 주어진 키의 항목을 선택하는 이벤트를 만듭니다.
+    pub fn select(&self, key: impl ToString) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "Select",
+            vec![
+                ("Id", self.id().to_string()),
+                ("Key", key.to_string()),
+                ("ByEnter", "false".to_string()),
+            ],
+        )
+    }
+}
+
+/// 콤보 박스와 같은 문서에서 주어진 ID의 ListBox를 찾아 항목을 읽습니다.
+/// 항목은 `data-itemkey`, `data-itemvalue1` 속성을 가진 요소이며, 키의 앞뒤 공백은 제거합니다.
+fn list_box_items(element: ElementRef, list_box_id: &str) -> Vec<ComboBoxItem> {
+    let Some(list_box) = element
+        .tree()
+        .root()
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .find(|node| node.value().attr("id") == Some(list_box_id))
+    else {
+        return Vec::new();
+    };
+
+    list_box
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter_map(|item| {
+            let key = item.value().attr("data-itemkey")?;
+            let text = item
+                .value()
+                .attr("data-itemvalue1")
+                .map(str::to_string)
+                .unwrap_or_else(|| element_text(item));
+            Some(ComboBoxItem {
+                key: key.trim().to_string(),
+                text,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use scraper::ElementRef;
+
+use super::{enqueue_event, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 입력 필드 (`ct="I"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputField {
+    control: Control,
+    value: String,
+    read_only: bool,
+}
+
+impl Element for InputField {
+    const CONTROL_TYPE: &'static str = "I";
+    const NAME: &'static str = "InputField";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(InputField {
+            control: Control::from_element::<Self>(element)?,
+            value: element
+                .value()
+                .attr("value")
+                .unwrap_or_default()
+                .to_string(),
+            read_only: element.value().attr("readonly").is_some(),
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl InputField {
+    /// 현재 입력된 값
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// 읽기 전용 여부
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// 입력 값을 바꾸는 이벤트를 만듭니다. 다음 submit 이벤트와 함께 전송됩니다.
+    pub fn change(&self, value: impl Into<String>) -> SapEvent {
+        enqueue_event::<Self>(
+            &self.control,
+            "Change",
+            vec![("Id", self.id().to_string()), ("Value", value.into())],
+        )
+    }
+
+    /// 입력 필드에서 Enter 키를 누르는 이벤트를 만듭니다.
+    pub fn enter(&self) -> SapEvent {
+        submit_event::<Self>(&self.control, "Enter", vec![("Id", self.id().to_string())])
+    }
+}
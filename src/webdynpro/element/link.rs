@@ -0,0 +1,47 @@
+use scraper::ElementRef;
+
+use super::{element_text, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 동작을 실행하는 링크 (`ct="LN"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    control: Control,
+    text: String,
+}
+
+impl Element for Link {
+    const CONTROL_TYPE: &'static str = "LN";
+    const NAME: &'static str = "Link";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(Link {
+            control: Control::from_element::<Self>(element)?,
+            text: element_text(element),
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl Link {
+    /// 링크 텍스트
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 링크를 누르는 이벤트를 만듭니다.
+    pub fn activate(&self) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "Activate",
+            vec![
+                ("Id", self.id().to_string()),
+                ("Ctrl", "false".to_string()),
+                ("Shift", "false".to_string()),
+            ],
+        )
+    }
+}
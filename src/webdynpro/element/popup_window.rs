@@ -0,0 +1,46 @@
+use scraper::{ElementRef, Selector};
+
+use super::{element_text, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 팝업 창 (`ct="PW"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PopupWindow {
+    control: Control,
+    title: String,
+}
+
+impl Element for PopupWindow {
+    const CONTROL_TYPE: &'static str = "PW";
+    const NAME: &'static str = "PopupWindow";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        let title_selector = Selector::parse(".lsPopupWindow__title, [ct=\"TITLE\"]").unwrap();
+        let title = element
+            .select(&title_selector)
+            .next()
+            .map(element_text)
+            .unwrap_or_default();
+
+        Ok(PopupWindow {
+            control: Control::from_element::<Self>(element)?,
+            title,
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl PopupWindow {
+    /// 팝업 창 제목
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// 팝업 창을 닫는 이벤트를 만듭니다.
+    pub fn close(&self) -> SapEvent {
+        submit_event::<Self>(&self.control, "Close", vec![("Id", self.id().to_string())])
+    }
+}
@@ -0,0 +1,46 @@
+use scraper::ElementRef;
+
+use super::{element_text, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 라디오 버튼 (`ct="R"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadioButton {
+    control: Control,
+    text: String,
+    selected: bool,
+}
+
+impl Element for RadioButton {
+    const CONTROL_TYPE: &'static str = "R";
+    const NAME: &'static str = "RadioButton";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(RadioButton {
+            control: Control::from_element::<Self>(element)?,
+            text: element_text(element),
+            selected: element.value().attr("aria-checked") == Some("true"),
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl RadioButton {
+    /// 라디오 버튼의 레이블
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 선택 여부
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// 라디오 버튼을 선택하는 이벤트를 만듭니다.
+    pub fn select(&self) -> SapEvent {
+        submit_event::<Self>(&self.control, "Select", vec![("Id", self.id().to_string())])
+    }
+}
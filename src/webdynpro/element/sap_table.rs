@@ -0,0 +1,326 @@
+use std::str::FromStr;
+
+use scraper::{ElementRef, Selector};
+
+use super::{element_text, id_selector, submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// `lsdata`의 전체 행 수, 열 수 키
+const LSDATA_ROW_COUNT: &str = "2";
+const LSDATA_COLUMN_COUNT: &str = "3";
+
+/// 헤더 셀의 `subct` 속성 값
+const HEADER_CELL_SUBCT: &str = "STHC";
+
+/// SAP 테이블 (`ct="ST"`)
+///
+/// 헤더 행의 셀 텍스트를 열 이름으로 삼아, 각 행을 열 이름으로 조회할 수 있도록 읽어 둡니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SapTable {
+    control: Control,
+    row_count: Option<u32>,
+    column_count: Option<u32>,
+    headers: Vec<String>,
+    rows: Vec<SapTableRow>,
+}
+
+impl Element for SapTable {
+    const CONTROL_TYPE: &'static str = "ST";
+    const NAME: &'static str = "SapTable";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        let control = Control::from_element::<Self>(element)?;
+        let lsdata = control.lsdata();
+
+        let (headers, rows) = match content_body(element, control.id()) {
+            Some(tbody) => read_body(tbody, control.id()),
+            None => (Vec::new(), Vec::new()),
+        };
+
+        Ok(SapTable {
+            row_count: lsdata
+                .get_number(LSDATA_ROW_COUNT)
+                .map(|count| count as u32),
+            column_count: lsdata
+                .get_number(LSDATA_COLUMN_COUNT)
+                .map(|count| count as u32),
+            headers,
+            rows,
+            control,
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl SapTable {
+    /// 서버에 있는 전체 행 수 (화면에 보이지 않는 행 포함)
+    pub fn row_count(&self) -> Option<u32> {
+        self.row_count
+    }
+
+    /// 열 수
+    pub fn column_count(&self) -> Option<u32> {
+        self.column_count
+    }
+
+    /// 헤더 행의 열 이름 목록 (화면에 표시된 순서)
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    /// 현재 화면에 표시된 데이터 행 목록
+    pub fn rows(&self) -> &[SapTableRow] {
+        &self.rows
+    }
+
+    /// 주어진 순번의 행을 선택하는 이벤트를 만듭니다.
+    pub fn row_select(&self, row_index: u32) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "RowSelect",
+            vec![
+                ("Id", self.id().to_string()),
+                ("RowIndex", row_index.to_string()),
+                ("RowUserData", String::new()),
+                ("RowSelected", "true".to_string()),
+                ("AccessType", "STANDARD".to_string()),
+                ("TriggeredByKeyboardNavigation", "false".to_string()),
+            ],
+        )
+    }
+
+    /// 주어진 행 번호(`rr`)의 행부터 보이도록 세로로 스크롤하는 이벤트를 만듭니다.
+    /// 서버는 화면에 보이는 행만 그려 보내므로, 나머지 행은 이 이벤트로 받아와야 합니다.
+    pub fn vertical_scroll(&self, first_visible_item_index: u32) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "VerticalScroll",
+            vec![
+                ("Id", self.id().to_string()),
+                (
+                    "FirstVisibleItemIndex",
+                    first_visible_item_index.to_string(),
+                ),
+                ("CellId", String::new()),
+                ("AccessType", "SCROLLBAR".to_string()),
+                ("SelectionFollowFocus", "false".to_string()),
+                ("Shift", "false".to_string()),
+                ("Ctrl", "false".to_string()),
+                ("Alt", "false".to_string()),
+            ],
+        )
+    }
+}
+
+/// SAP 테이블의 데이터 행 하나
+#[derive(Debug, Clone, PartialEq)]
+pub struct SapTableRow {
+    table_id: String,
+    index: Option<u32>,
+    cells: Vec<(String, SapTableCell)>,
+}
+
+impl SapTableRow {
+    /// 서버가 매긴 행 번호 (`rr` 속성)
+    pub fn index(&self) -> Option<u32> {
+        self.index
+    }
+
+    /// 주어진 열 이름의 셀을 반환합니다. 같은 이름의 열이 여러 개면 첫 번째 열을 반환합니다.
+    pub fn get(&self, header: &str) -> Option<&SapTableCell> {
+        self.cells
+            .iter()
+            .find(|(name, _)| name == header)
+            .map(|(_, cell)| cell)
+    }
+
+    /// 주어진 열 이름의 셀을 반환하고, 열이 없으면 오류를 반환합니다.
+    pub fn cell(&self, header: &str) -> Result<&SapTableCell, ElementError> {
+        self.get(header).ok_or_else(|| ElementError::MissingColumn {
+            id: self.table_id.clone(),
+            column: header.to_string(),
+        })
+    }
+
+    /// 주어진 열의 텍스트를 반환합니다.
+    pub fn text(&self, header: &str) -> Result<&str, ElementError> {
+        Ok(self.cell(header)?.text())
+    }
+
+    /// 주어진 열의 텍스트를 원하는 타입으로 변환해 반환합니다.
+    pub fn parse<T: FromStr>(&self, header: &str) -> Result<T, ElementError> {
+        let text = self.text(header)?;
+        text.parse().map_err(|_| self.invalid_cell(header, text))
+    }
+
+    /// 셀 값을 해석할 수 없을 때의 오류를 만듭니다.
+    pub fn invalid_cell(&self, header: &str, value: &str) -> ElementError {
+        ElementError::InvalidCell {
+            id: self.table_id.clone(),
+            column: header.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// (열 이름, 셀) 쌍을 화면에 표시된 순서대로 반환합니다.
+    pub fn cells(&self) -> impl Iterator<Item = (&str, &SapTableCell)> {
+        self.cells.iter().map(|(name, cell)| (name.as_str(), cell))
+    }
+}
+
+/// 셀 안에 들어 있는 컨트롤의 종류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SapTableCellKind {
+    /// 텍스트만 있는 셀 (`TextView` 포함)
+    Text,
+    Button,
+    CheckBox,
+    RadioButton,
+    Link,
+    InputField,
+    ComboBox,
+    /// 그 밖의 컨트롤 (`ct` 속성 값)
+    Other(String),
+}
+
+impl SapTableCellKind {
+    fn from_control_type(control_type: &str) -> Self {
+        match control_type {
+            "TV" => SapTableCellKind::Text,
+            "B" => SapTableCellKind::Button,
+            "C" => SapTableCellKind::CheckBox,
+            "R" => SapTableCellKind::RadioButton,
+            "LN" => SapTableCellKind::Link,
+            "I" => SapTableCellKind::InputField,
+            "CB" => SapTableCellKind::ComboBox,
+            other => SapTableCellKind::Other(other.to_string()),
+        }
+    }
+}
+
+/// SAP 테이블의 셀 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SapTableCell {
+    kind: SapTableCellKind,
+    text: String,
+    control_ids: Vec<String>,
+}
+
+impl SapTableCell {
+    fn from_element(element: ElementRef) -> Self {
+        let controls: Vec<ElementRef> = element
+            .descendants()
+            .skip(1)
+            .filter_map(ElementRef::wrap)
+            .filter(|child| child.value().attr("ct").is_some())
+            .collect();
+
+        let kind = controls
+            .iter()
+            .map(|control| {
+                SapTableCellKind::from_control_type(control.value().attr("ct").unwrap_or_default())
+            })
+            .find(|kind| *kind != SapTableCellKind::Text)
+            .unwrap_or(SapTableCellKind::Text);
+
+        SapTableCell {
+            kind,
+            text: element_text(element),
+            control_ids: controls
+                .iter()
+                .filter_map(|control| control.value().attr("id"))
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// 셀에 들어 있는 컨트롤의 종류
+    pub fn kind(&self) -> &SapTableCellKind {
+        &self.kind
+    }
+
+    /// 셀의 텍스트 (공백 정리)
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 셀 안에 들어 있는 컨트롤들의 ID
+    pub fn control_ids(&self) -> &[String] {
+        &self.control_ids
+    }
+
+    /// 셀 안의 첫 번째 컨트롤 ID
+    pub fn control_id(&self) -> Option<&str> {
+        self.control_ids.first().map(String::as_str)
+    }
+}
+
+/// 테이블의 내용이 들어 있는 `{id}-contentTBody` 요소를 찾습니다.
+fn content_body<'a>(table: ElementRef<'a>, id: &str) -> Option<ElementRef<'a>> {
+    let tbody_selector = Selector::parse("tbody").unwrap();
+
+    id_selector(&format!("{}-contentTBody", id))
+        .and_then(|selector| table.select(&selector).next())
+        .or_else(|| table.select(&tbody_selector).next())
+}
+
+/// 헤더 행에서 열 이름을 읽고, 나머지 행을 열 이름과 셀의 쌍으로 읽습니다.
+fn read_body(tbody: ElementRef, table_id: &str) -> (Vec<String>, Vec<SapTableRow>) {
+    let rows: Vec<ElementRef> = tbody
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(|child| child.value().name() == "tr")
+        .collect();
+
+    // 헤더 셀이 있는 행을 헤더로 삼고, 없으면 첫 번째 행을 헤더로 취급
+    let header_position = rows
+        .iter()
+        .position(|row| cells(*row).any(is_header_cell))
+        .unwrap_or(0);
+    let Some(header_row) = rows.get(header_position) else {
+        return (Vec::new(), Vec::new());
+    };
+    let headers: Vec<String> = cells(*header_row).map(element_text).collect();
+
+    let rows = rows
+        .iter()
+        .enumerate()
+        // rr="0"인 행은 데이터가 없는 자리 표시용 행
+        .filter(|(position, row)| {
+            *position != header_position && row.value().attr("rr") != Some("0")
+        })
+        .filter_map(|(_, row)| {
+            let cells: Vec<(String, SapTableCell)> = cells(*row)
+                .enumerate()
+                .map(|(column, cell)| {
+                    let header = headers.get(column).cloned().unwrap_or_default();
+                    (header, SapTableCell::from_element(cell))
+                })
+                .collect();
+            if cells.is_empty() {
+                return None;
+            }
+
+            Some(SapTableRow {
+                table_id: table_id.to_string(),
+                index: row.value().attr("rr").and_then(|rr| rr.parse().ok()),
+                cells,
+            })
+        })
+        .collect();
+
+    (headers, rows)
+}
+
+fn cells<'a>(row: ElementRef<'a>) -> impl Iterator<Item = ElementRef<'a>> {
+    row.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|child| matches!(child.value().name(), "td" | "th"))
+}
+
+fn is_header_cell(cell: ElementRef) -> bool {
+    cell.value().name() == "th" || cell.value().attr("subct") == Some(HEADER_CELL_SUBCT)
+}
@@ -0,0 +1,41 @@
+use scraper::ElementRef;
+
+use super::{submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 탭 스트립 (`ct="TS"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabStrip {
+    control: Control,
+}
+
+impl Element for TabStrip {
+    const CONTROL_TYPE: &'static str = "TS";
+    const NAME: &'static str = "TabStrip";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(TabStrip {
+            control: Control::from_element::<Self>(element)?,
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl TabStrip {
+    /// 주어진 ID와 순번의 탭을 선택하는 이벤트를 만듭니다.
+    pub fn tab_select(&self, item_id: &str, item_index: u32) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "TabSelect",
+            vec![
+                ("Id", self.id().to_string()),
+                ("ItemId", item_id.to_string()),
+                ("ItemIndex", item_index.to_string()),
+                ("FirstVisibleItemIndex", "0".to_string()),
+            ],
+        )
+    }
+}
@@ -0,0 +1,33 @@
+use scraper::ElementRef;
+
+use super::{element_text, Control, Element, ElementError};
+
+/// 텍스트를 표시하는 컨트롤 (`ct="TV"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextView {
+    control: Control,
+    text: String,
+}
+
+impl Element for TextView {
+    const CONTROL_TYPE: &'static str = "TV";
+    const NAME: &'static str = "TextView";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(TextView {
+            control: Control::from_element::<Self>(element)?,
+            text: element_text(element),
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl TextView {
+    /// 표시된 텍스트
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
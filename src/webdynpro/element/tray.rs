@@ -0,0 +1,46 @@
+use scraper::ElementRef;
+
+use super::{submit_event, Control, Element, ElementError};
+use crate::webdynpro::event::SapEvent;
+
+/// 접고 펼칠 수 있는 트레이 (`ct="TY"`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tray {
+    control: Control,
+    expanded: bool,
+}
+
+impl Element for Tray {
+    const CONTROL_TYPE: &'static str = "TY";
+    const NAME: &'static str = "Tray";
+
+    fn from_element(element: ElementRef) -> Result<Self, ElementError> {
+        Ok(Tray {
+            control: Control::from_element::<Self>(element)?,
+            expanded: element.value().attr("aria-expanded") != Some("false"),
+        })
+    }
+
+    fn control(&self) -> &Control {
+        &self.control
+    }
+}
+
+impl Tray {
+    /// 펼쳐져 있는지 여부
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// 트레이를 펼치거나 접는 이벤트를 만듭니다.
+    pub fn toggle(&self, expanded: bool) -> SapEvent {
+        submit_event::<Self>(
+            &self.control,
+            "Toggle",
+            vec![
+                ("Id", self.id().to_string()),
+                ("Expanded", expanded.to_string()),
+            ],
+        )
+    }
+}
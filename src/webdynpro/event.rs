@@ -1,10 +1,12 @@
 use std::{
     collections::{HashMap, VecDeque},
     fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
 };
 
 use derive_builder::Builder;
 use regex::Regex;
+use thiserror::Error;
 
 const SAP_ENCODED_NEWLINE: &str = "~E001";
 const SAP_ENCODED_OPEN_BRACE: &str = "~E002";
@@ -12,6 +14,139 @@ const SAP_ENCODED_CLOSE_BRACE: &str = "~E003";
 const SAP_ENCODED_COLON: &str = "~E004";
 const SAP_ENCODED_COMMA: &str = "~E005";
 
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SapEventParseError {
+    #[error("이벤트 이름이 `컨트롤_동작` 형식이 아닙니다: {0}")]
+    InvalidEventName(String),
+    #[error("{event} 이벤트의 파라미터 목록이 3개가 아닙니다. ({found}개)")]
+    InvalidParameterGroups { event: String, found: usize },
+    #[error("{event} 이벤트의 파라미터 목록이 올바르게 닫히지 않았습니다.")]
+    UnclosedParameterGroup { event: String },
+    #[error("{event} 이벤트의 파라미터에 키와 값의 구분자가 없습니다: {parameter}")]
+    InvalidParameter { event: String, parameter: String },
+    #[error("{event} 이벤트의 파라미터 목록에 {key} 키가 중복되어 있습니다.")]
+    DuplicateParameter { event: String, key: String },
+    #[error("올바르지 않은 인코딩 문자열입니다: {0}")]
+    InvalidEncoding(String),
+}
+
+/// SSR 클라이언트 폼의 ID
+const SSR_CLIENT_FORM_ID: &str = "sap.client.SsrClient.form";
+
+/// 브라우저의 SSR 클라이언트가 ucf 파라미터를 보내는 순서
+/// 여기에 없는 키는 이 키들 뒤에 추가된 순서대로 보냅니다.
+const CANONICAL_UCF_PARAMETER_ORDER: [&str; 6] = [
+    "ResponseData",
+    "ClientAction",
+    "EnqueueCardinality",
+    "Delay",
+    "TransportMethod",
+    "PrepareScript",
+];
+
+/// 추가된 순서를 유지하는 이벤트 파라미터 목록
+/// 같은 키를 다시 추가하면 원래 자리의 값을 교체합니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SapEventParameters {
+    entries: Vec<(String, String)>,
+}
+
+impl SapEventParameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 파라미터를 추가하고, 같은 키가 이미 있으면 그 자리의 값을 교체한 뒤 이전 값을 반환합니다.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        let key = key.into();
+        let value = value.into();
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_key, _)| *entry_key == key)
+        {
+            Some((_, entry_value)) => Some(std::mem::replace(entry_value, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let position = self
+            .entries
+            .iter()
+            .position(|(entry_key, _)| entry_key == key)?;
+        Some(self.entries.remove(position).1)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// (키, 값) 쌍을 추가된 순서대로 반환합니다.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// 잘 알려진 ucf 키는 브라우저와 같은 순서로, 나머지는 추가된 순서대로 반환합니다.
+    fn canonical_ucf_iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        let mut entries: Vec<(&str, &str)> = self.iter().collect();
+        entries.sort_by_key(|(key, _)| {
+            CANONICAL_UCF_PARAMETER_ORDER
+                .iter()
+                .position(|canonical| canonical == key)
+                .unwrap_or(CANONICAL_UCF_PARAMETER_ORDER.len())
+        });
+        entries.into_iter()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> Extend<(K, V)> for SapEventParameters {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for SapEventParameters {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut parameters = SapEventParameters::new();
+        parameters.extend(iter);
+        parameters
+    }
+}
+
+impl IntoIterator for SapEventParameters {
+    type Item = (String, String);
+    type IntoIter = std::vec::IntoIter<(String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+/// 두 이벤트는 직렬화했을 때 같은 문자열이 되면 같은 것으로 봅니다.
+/// ucf 파라미터는 [`Display`]가 브라우저 순서로 다시 정렬하므로, 추가된 순서가 아니라 그 순서로 비교합니다.
 #[derive(Debug, Builder, Clone)]
 #[builder(setter(into))]
 pub struct SapEvent {
@@ -20,59 +155,182 @@ pub struct SapEvent {
 
     #[builder(setter(each = "add_parameter"))]
     #[builder(default)]
-    pub parameters: HashMap<String, String>,
+    pub parameters: SapEventParameters,
 
     #[builder(setter(each = "add_ucf_parameter"))]
     #[builder(default)]
-    pub ucf_parameters: HashMap<String, String>,
+    pub ucf_parameters: SapEventParameters,
 
     #[builder(setter(each = "add_custom_parameter"))]
     #[builder(default)]
-    pub custom_parameters: HashMap<String, String>,
+    pub custom_parameters: SapEventParameters,
+}
+
+impl PartialEq for SapEvent {
+    fn eq(&self, other: &Self) -> bool {
+        self.event == other.event
+            && self.control == other.control
+            && self.parameters == other.parameters
+            && self
+                .ucf_parameters
+                .canonical_ucf_iter()
+                .eq(other.ucf_parameters.canonical_ucf_iter())
+            && self.custom_parameters == other.custom_parameters
+    }
 }
 
+impl Eq for SapEvent {}
+
 impl Display for SapEvent {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "{}_{}", self.event, self.control)?;
 
-        write!(f, "{}", SAP_ENCODED_OPEN_BRACE)?;
-        for (i, (key, value)) in self.parameters.iter().enumerate() {
-            write!(f, "{}{}{}", key, SAP_ENCODED_COLON, encode_sap_event(value))?;
-            if i < self.parameters.len() - 1 {
-                write!(f, "{}", SAP_ENCODED_COMMA)?;
-            }
+        write_parameters(f, self.parameters.iter())?;
+        write_parameters(f, self.ucf_parameters.canonical_ucf_iter())?;
+        write_parameters(f, self.custom_parameters.iter())?;
+
+        Ok(())
+    }
+}
+
+/// 파라미터 목록을 `~E002키~E004값~E005...~E003` 형식으로 씁니다.
+fn write_parameters<'a>(
+    f: &mut Formatter<'_>,
+    parameters: impl Iterator<Item = (&'a str, &'a str)>,
+) -> FmtResult {
+    write!(f, "{}", SAP_ENCODED_OPEN_BRACE)?;
+    for (i, (key, value)) in parameters.enumerate() {
+        if i > 0 {
+            write!(f, "{}", SAP_ENCODED_COMMA)?;
         }
-        write!(f, "{}", SAP_ENCODED_CLOSE_BRACE)?;
+        write!(f, "{}{}{}", key, SAP_ENCODED_COLON, encode_sap_event(value))?;
+    }
+    write!(f, "{}", SAP_ENCODED_CLOSE_BRACE)
+}
 
-        write!(f, "{}", SAP_ENCODED_OPEN_BRACE)?;
-        for (i, (key, value)) in self.ucf_parameters.iter().enumerate() {
-            write!(f, "{}{}{}", key, SAP_ENCODED_COLON, encode_sap_event(value))?;
-            if i < self.ucf_parameters.len() - 1 {
-                write!(f, "{}", SAP_ENCODED_COMMA)?;
-            }
+impl FromStr for SapEvent {
+    type Err = SapEventParseError;
+
+    /// `Button_Press~E002...~E003~E002...~E003~E002...~E003` 형식의 인코딩된 이벤트를 해석합니다.
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        let (name, groups) = match encoded.find(SAP_ENCODED_OPEN_BRACE) {
+            Some(position) => encoded.split_at(position),
+            None => (encoded, ""),
+        };
+        let (event, control) = name
+            .split_once('_')
+            .filter(|(event, control)| !event.is_empty() && !control.is_empty())
+            .ok_or_else(|| SapEventParseError::InvalidEventName(name.to_string()))?;
+
+        let mut groups = parse_parameter_groups(event, groups)?;
+        if groups.len() != 3 {
+            return Err(SapEventParseError::InvalidParameterGroups {
+                event: name.to_string(),
+                found: groups.len(),
+            });
         }
-        write!(f, "{}", SAP_ENCODED_CLOSE_BRACE)?;
+        let custom_parameters = groups.pop().unwrap_or_default();
+        let ucf_parameters = groups.pop().unwrap_or_default();
+        let parameters = groups.pop().unwrap_or_default();
 
-        write!(f, "{}", SAP_ENCODED_OPEN_BRACE)?;
-        for (i, (key, value)) in self.custom_parameters.iter().enumerate() {
-            write!(f, "{}{}{}", key, SAP_ENCODED_COLON, encode_sap_event(value))?;
-            if i < self.custom_parameters.len() - 1 {
-                write!(f, "{}", SAP_ENCODED_COMMA)?;
+        Ok(SapEvent {
+            event: event.to_string(),
+            control: control.to_string(),
+            parameters,
+            ucf_parameters,
+            custom_parameters,
+        })
+    }
+}
+
+/// 이벤트 이름 뒤의 `~E002키~E004값~E005...~E003` 목록들을 차례로 해석합니다.
+fn parse_parameter_groups(
+    event: &str,
+    mut groups: &str,
+) -> Result<Vec<SapEventParameters>, SapEventParseError> {
+    let mut parsed = Vec::new();
+
+    while !groups.is_empty() {
+        let Some(rest) = groups.strip_prefix(SAP_ENCODED_OPEN_BRACE) else {
+            return Err(SapEventParseError::InvalidEncoding(groups.to_string()));
+        };
+        let (group, rest) = rest.split_once(SAP_ENCODED_CLOSE_BRACE).ok_or_else(|| {
+            SapEventParseError::UnclosedParameterGroup {
+                event: event.to_string(),
             }
-        }
-        write!(f, "{}", SAP_ENCODED_CLOSE_BRACE)?;
+        })?;
 
-        Ok(())
+        let mut parameters = SapEventParameters::new();
+        for parameter in group.split(SAP_ENCODED_COMMA).filter(|p| !p.is_empty()) {
+            let (key, value) = parameter.split_once(SAP_ENCODED_COLON).ok_or_else(|| {
+                SapEventParseError::InvalidParameter {
+                    event: event.to_string(),
+                    parameter: parameter.to_string(),
+                }
+            })?;
+            // 중복된 키를 그대로 합치면 다시 인코딩했을 때 원래 문자열과 달라지므로 오류로 처리
+            if parameters.contains_key(key) {
+                return Err(SapEventParseError::DuplicateParameter {
+                    event: event.to_string(),
+                    key: key.to_string(),
+                });
+            }
+            parameters.insert(key, decode_sap_event_value(value)?);
+        }
+        parsed.push(parameters);
+        groups = rest;
     }
+
+    Ok(parsed)
 }
 
-#[derive(Debug, Builder)]
+#[derive(Debug, Clone, PartialEq, Eq, Builder)]
 pub struct SapEventQueue {
     #[builder(setter(each = "add_event"))]
     #[builder(default)]
     queue: VecDeque<SapEvent>,
 }
 
+impl SapEventQueue {
+    /// 브라우저의 SSR 클라이언트처럼 사용자 이벤트 앞에 `ClientInspector_Notify`를,
+    /// 뒤에 포커스 정보를 담은 `Form_Request`를 붙인 이벤트 큐를 만듭니다.
+    pub fn with_envelope(events: impl IntoIterator<Item = SapEvent>) -> SapEventQueue {
+        let events: Vec<SapEvent> = events.into_iter().collect();
+        let focused_id = events
+            .iter()
+            .rev()
+            .find_map(|event| event.parameters.get("Id"))
+            .cloned();
+
+        let mut queue = VecDeque::with_capacity(events.len() + 2);
+        queue.push_back(client_inspector_notify());
+        queue.extend(events);
+        queue.push_back(form_request(focused_id.as_deref()));
+
+        SapEventQueue { queue }
+    }
+
+    /// 큐에 담긴 이벤트를 전송 순서대로 반환합니다.
+    pub fn events(&self) -> impl Iterator<Item = &SapEvent> {
+        self.queue.iter()
+    }
+}
+
+impl FromStr for SapEventQueue {
+    type Err = SapEventParseError;
+
+    /// 브라우저가 보낸 `SAPEVENTQUEUE` 값을 이벤트 목록으로 해석합니다.
+    fn from_str(encoded: &str) -> Result<Self, Self::Err> {
+        let queue = encoded
+            .split(SAP_ENCODED_NEWLINE)
+            .filter(|event| !event.is_empty())
+            .map(SapEvent::from_str)
+            .collect::<Result<_, _>>()?;
+
+        Ok(SapEventQueue { queue })
+    }
+}
+
 impl Display for SapEventQueue {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         for (i, event) in self.queue.iter().enumerate() {
@@ -86,20 +344,90 @@ impl Display for SapEventQueue {
     }
 }
 
+/// 사용자 이벤트보다 먼저 대기열에 쌓이는 클라이언트 상태 알림
+/// 세션 유지 요청도 브라우저와 같은 내용의 이 알림을 보냅니다.
+pub(crate) fn client_inspector_notify() -> SapEvent {
+    SapEvent {
+        event: "ClientInspector".to_string(),
+        control: "Notify".to_string(),
+        parameters: [("Id", "WD01"), ("Data", "CssMatchesHtmlVersion:TRUE")]
+            .into_iter()
+            .collect(),
+        ucf_parameters: [("ResponseData", "delta"), ("EnqueueCardinality", "single")]
+            .into_iter()
+            .collect(),
+        custom_parameters: SapEventParameters::new(),
+    }
+}
+
+/// 대기열의 이벤트를 서버로 보내는 폼 제출 이벤트
+fn form_request(focused_id: Option<&str>) -> SapEvent {
+    let focus_info = match focused_id {
+        Some(id) => format!(r#"@{{"sFocussedId":"{}"}}"#, id),
+        None => "@{}".to_string(),
+    };
+
+    SapEvent {
+        event: "Form".to_string(),
+        control: "Request".to_string(),
+        parameters: [
+            ("Id", SSR_CLIENT_FORM_ID.to_string()),
+            ("Async", "false".to_string()),
+            ("FocusInfo", focus_info),
+            ("Hash", String::new()),
+            ("DomChanged", "false".to_string()),
+            ("IsDirty", "false".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+        ucf_parameters: [("ResponseData", "delta")].into_iter().collect(),
+        custom_parameters: SapEventParameters::new(),
+    }
+}
+
 /// 문자열을 sap event가 URL에서 전달되는 방식으로 인코딩합니다.
+/// 구분자와 헷갈리지 않도록 `~`도 인코딩하며, BMP 밖의 문자는 UTF-16 단위로 나누어 인코딩합니다.
 pub fn encode_sap_event(input: &str) -> String {
     let mut encoded = String::new();
 
     for ch in input.chars() {
         match ch {
-            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' | '~' => encoded.push(ch),
-            _ => encoded.push_str(format!("~{:04X}", ch as u32).as_str()),
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' => encoded.push(ch),
+            _ => {
+                let mut units = [0; 2];
+                for unit in ch.encode_utf16(&mut units) {
+                    encoded.push_str(format!("~{:04X}", unit).as_str());
+                }
+            }
         }
     }
 
     encoded
 }
 
+/// 파라미터 값 하나의 `~XXXX` 인코딩을 엄격하게 디코딩합니다.
+fn decode_sap_event_value(encoded: &str) -> Result<String, SapEventParseError> {
+    let invalid = || SapEventParseError::InvalidEncoding(encoded.to_string());
+    let mut units = Vec::new();
+    let mut chars = encoded.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '~' {
+            let mut buffer = [0; 2];
+            units.extend_from_slice(ch.encode_utf16(&mut buffer));
+            continue;
+        }
+
+        let hex: String = chars.by_ref().take(4).collect();
+        if hex.len() != 4 {
+            return Err(invalid());
+        }
+        units.push(u16::from_str_radix(&hex, 16).map_err(|_| invalid())?);
+    }
+
+    String::from_utf16(&units).map_err(|_| invalid())
+}
+
 /// sap event encoding된 문자열을 디코딩합니다.
 pub fn decode_sap_event(encoded: &str) -> String {
     // sap event encoding에서 쓰이는 특수 문자 처리
@@ -0,0 +1,369 @@
+use std::{iter::Peekable, str::Chars};
+
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LsDataError {
+    #[error("속성 값이 도중에 끝났습니다.")]
+    UnexpectedEnd,
+    #[error("{position}번째 위치에 예상하지 못한 문자 '{found}'가 있습니다.")]
+    UnexpectedCharacter { position: usize, found: char },
+    #[error("올바르지 않은 이스케이프 문자열입니다: {0}")]
+    InvalidEscape(String),
+    #[error("최상위 값이 객체가 아닙니다.")]
+    NotAnObject,
+}
+
+/// `lsdata`, `lsevents` 속성에 쓰이는 JavaScript 객체 형식의 값
+#[derive(Debug, Clone, PartialEq)]
+pub enum LsValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<LsValue>),
+    /// 키 순서를 유지하는 객체
+    Object(Vec<(String, LsValue)>),
+}
+
+impl LsValue {
+    /// 주어진 문자열을 값 하나로 해석합니다.
+    pub fn parse(source: &str) -> Result<Self, LsDataError> {
+        let mut parser = Parser {
+            chars: source.chars().peekable(),
+            position: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.next() {
+            None => Ok(value),
+            Some(found) => Err(parser.unexpected(found)),
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            LsValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            LsValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            LsValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// 이벤트 파라미터로 보낼 때 쓰는 문자열 표현
+    pub fn to_parameter(&self) -> String {
+        match self {
+            LsValue::Null => String::new(),
+            LsValue::Bool(value) => value.to_string(),
+            LsValue::Number(value) if value.fract() == 0.0 => format!("{}", *value as i64),
+            LsValue::Number(value) => value.to_string(),
+            LsValue::String(value) => value.clone(),
+            LsValue::Array(_) | LsValue::Object(_) => String::new(),
+        }
+    }
+}
+
+/// 컨트롤의 `lsdata` 속성: 컨트롤의 실제 상태(값, 활성화 여부, 행 수 등)를 키별로 담습니다.
+/// 키는 대부분 컨트롤 종류마다 정해진 순번(`"0"`, `"1"`, ...)입니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LsData {
+    entries: Vec<(String, LsValue)>,
+}
+
+impl LsData {
+    pub fn parse(source: &str) -> Result<Self, LsDataError> {
+        match LsValue::parse(source)? {
+            LsValue::Object(entries) => Ok(LsData { entries }),
+            _ => Err(LsDataError::NotAnObject),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&LsValue> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(LsValue::as_str)
+    }
+
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(LsValue::as_bool)
+    }
+
+    pub fn get_number(&self, key: &str) -> Option<f64> {
+        self.get(key).and_then(LsValue::as_number)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &LsValue)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+/// 컨트롤이 받는 이벤트 하나와, 그 이벤트를 보낼 때 함께 보내야 하는 파라미터
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LsEvent {
+    pub ucf_parameters: Vec<(String, String)>,
+    pub custom_parameters: Vec<(String, String)>,
+}
+
+/// 컨트롤의 `lsevents` 속성: `{'Press':[{ucf 파라미터},{사용자 정의 파라미터}], ...}`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LsEvents {
+    events: Vec<(String, LsEvent)>,
+}
+
+impl LsEvents {
+    pub fn parse(source: &str) -> Result<Self, LsDataError> {
+        let LsValue::Object(entries) = LsValue::parse(source)? else {
+            return Err(LsDataError::NotAnObject);
+        };
+
+        let events = entries
+            .into_iter()
+            .map(|(name, value)| {
+                let mut parameters = match value {
+                    LsValue::Array(parameters) => parameters.into_iter(),
+                    _ => Vec::new().into_iter(),
+                };
+                let event = LsEvent {
+                    ucf_parameters: parameter_list(parameters.next()),
+                    custom_parameters: parameter_list(parameters.next()),
+                };
+                (name, event)
+            })
+            .collect();
+
+        Ok(LsEvents { events })
+    }
+
+    /// 주어진 이름의 이벤트 정의를 반환합니다.
+    pub fn get(&self, name: &str) -> Option<&LsEvent> {
+        self.events
+            .iter()
+            .find(|(event_name, _)| event_name == name)
+            .map(|(_, event)| event)
+    }
+
+    /// 컨트롤이 주어진 이벤트를 받는지 확인합니다.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// 컨트롤이 받는 이벤트 이름 목록
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.events.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+fn parameter_list(value: Option<LsValue>) -> Vec<(String, String)> {
+    match value {
+        Some(LsValue::Object(entries)) => entries
+            .into_iter()
+            .map(|(key, value)| (key, value.to_parameter()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<char> {
+        let ch = self.chars.next()?;
+        self.position += 1;
+        Some(ch)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn unexpected(&self, found: char) -> LsDataError {
+        LsDataError::UnexpectedCharacter {
+            position: self.position,
+            found,
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), LsDataError> {
+        self.skip_whitespace();
+        match self.next() {
+            Some(ch) if ch == expected => Ok(()),
+            Some(found) => Err(self.unexpected(found)),
+            None => Err(LsDataError::UnexpectedEnd),
+        }
+    }
+
+    fn value(&mut self) -> Result<LsValue, LsDataError> {
+        self.skip_whitespace();
+        match self.peek().ok_or(LsDataError::UnexpectedEnd)? {
+            '{' => self.object(),
+            '[' => self.array(),
+            '\'' | '"' => Ok(LsValue::String(self.string()?)),
+            '-' | '0'..='9' | '.' => self.number(),
+            _ => match self.word()?.as_str() {
+                "true" => Ok(LsValue::Bool(true)),
+                "false" => Ok(LsValue::Bool(false)),
+                "null" | "undefined" => Ok(LsValue::Null),
+                word => Err(self.unexpected(word.chars().next().unwrap_or(' '))),
+            },
+        }
+    }
+
+    fn object(&mut self) -> Result<LsValue, LsDataError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek().ok_or(LsDataError::UnexpectedEnd)? {
+                '}' => {
+                    self.next();
+                    return Ok(LsValue::Object(entries));
+                }
+                '\'' | '"' => {
+                    let key = self.string()?;
+                    entries.push(self.entry_value(key)?);
+                }
+                _ => {
+                    let key = self.word()?;
+                    entries.push(self.entry_value(key)?);
+                }
+            }
+
+            self.skip_whitespace();
+            match self.next().ok_or(LsDataError::UnexpectedEnd)? {
+                ',' => continue,
+                '}' => return Ok(LsValue::Object(entries)),
+                found => return Err(self.unexpected(found)),
+            }
+        }
+    }
+
+    fn entry_value(&mut self, key: String) -> Result<(String, LsValue), LsDataError> {
+        self.expect(':')?;
+        Ok((key, self.value()?))
+    }
+
+    fn array(&mut self) -> Result<LsValue, LsDataError> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some(']') {
+                self.next();
+                return Ok(LsValue::Array(values));
+            }
+
+            values.push(self.value()?);
+
+            self.skip_whitespace();
+            match self.next().ok_or(LsDataError::UnexpectedEnd)? {
+                ',' => continue,
+                ']' => return Ok(LsValue::Array(values)),
+                found => return Err(self.unexpected(found)),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, LsDataError> {
+        let quote = self.next().ok_or(LsDataError::UnexpectedEnd)?;
+        let mut value = String::new();
+
+        loop {
+            match self.next().ok_or(LsDataError::UnexpectedEnd)? {
+                ch if ch == quote => return Ok(value),
+                '\\' => value.push(self.escape()?),
+                ch => value.push(ch),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, LsDataError> {
+        let ch = self.next().ok_or(LsDataError::UnexpectedEnd)?;
+        let hex_length = match ch {
+            'x' => 2,
+            'u' => 4,
+            'n' => return Ok('\n'),
+            't' => return Ok('\t'),
+            'r' => return Ok('\r'),
+            other => return Ok(other),
+        };
+
+        let hex: String = (0..hex_length).filter_map(|_| self.next()).collect();
+        u32::from_str_radix(&hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| LsDataError::InvalidEscape(format!("\\{}{}", ch, hex)))
+    }
+
+    fn number(&mut self) -> Result<LsValue, LsDataError> {
+        let mut literal = String::new();
+        while let Some(ch) = self.peek() {
+            if ch.is_ascii_digit() || matches!(ch, '-' | '+' | '.' | 'e' | 'E') {
+                literal.push(ch);
+                self.next();
+            } else {
+                break;
+            }
+        }
+
+        literal
+            .parse()
+            .map(LsValue::Number)
+            .map_err(|_| self.unexpected(literal.chars().next().unwrap_or(' ')))
+    }
+
+    /// 따옴표 없이 쓰인 객체 키 또는 `true`, `false` 같은 키워드
+    fn word(&mut self) -> Result<String, LsDataError> {
+        let mut word = String::new();
+        while let Some(ch) = self.peek() {
+            if ch.is_alphanumeric() || matches!(ch, '_' | '$' | '-' | '.') {
+                word.push(ch);
+                self.next();
+            } else {
+                break;
+            }
+        }
+
+        if word.is_empty() {
+            return Err(match self.peek() {
+                Some(found) => self.unexpected(found),
+                None => LsDataError::UnexpectedEnd,
+            });
+        }
+
+        Ok(word)
+    }
+}
@@ -0,0 +1,140 @@
+use regex::Regex;
+use scraper::{Html, Node, Selector};
+
+use super::response::is_updates_document;
+
+/// SAP 로그온 폼의 계정 입력 필드. 폼 안에 있는 입력 필드만 로그온 폼으로 판단합니다.
+const LOGON_FORM_SELECTOR: &str =
+    r#"form input[name="sap-user"], form input[name="sap-system-login"]"#;
+/// 실행 중인 WebDynpro 애플리케이션 페이지의 SSR 클라이언트 폼
+const SSR_CLIENT_FORM_SELECTOR: &str = r#"form[id="sap.client.SsrClient.form"]"#;
+
+/// 세션 만료 페이지에서 나타나는 문자열 목록
+const SESSION_EXPIRED_MARKERS: [&str; 4] = [
+    "session has expired",
+    "session timed out",
+    "세션이 만료",
+    "세션 시간이 초과",
+];
+
+/// 권한 부족으로 애플리케이션을 열 수 없을 때 SAP가 표시하는 메시지의 패턴 목록
+const AUTHORIZATION_DENIED_PATTERNS: [&str; 3] = [
+    r"no authorization (for|to start) (the )?application",
+    r"not authorized to (use|start) (the )?application",
+    r"애플리케이션[^.]{0,60}(실행|사용)할 권한이 없습니다",
+];
+
+/// 존재하지 않는 애플리케이션을 요청했을 때 SAP가 표시하는 메시지의 패턴 목록
+const APPLICATION_NOT_FOUND_PATTERNS: [&str; 3] = [
+    r"application \S+ does not exist",
+    r"web dynpro application \S+ not found",
+    r"애플리케이션 \S+[이가]? 존재하지 않습니다",
+];
+
+/// 오류 메시지에 포함할 페이지 발췌의 최대 길이 (문자 수)
+const SNIPPET_LENGTH: usize = 300;
+
+/// 응답 본문이 SAP 로그인 폼인지 확인합니다.
+/// 본문 어딘가에 필드 이름이 나오는 것이 아니라, `<form>` 안에 로그온 입력 필드가 있어야 합니다.
+pub fn is_login_page(body: &str) -> bool {
+    has_logon_form(&Html::parse_document(body))
+}
+
+/// 응답 본문이 SSO 세션 또는 WebDynpro 애플리케이션 세션이 만료되었음을 나타내는지 확인합니다.
+///
+/// 로그온 폼으로 되돌아간 경우와, SSR 클라이언트 폼이 없는 페이지의 본문 텍스트에 세션 만료 문구가
+/// 있는 경우를 만료로 판단합니다. 델타 응답(`<updates>`)과 실행 중인 애플리케이션 페이지는
+/// 내용에 같은 문구가 있어도 만료로 판단하지 않습니다.
+pub fn is_session_expired(body: &str) -> bool {
+    if is_updates_document(body) {
+        return false;
+    }
+
+    let document = Html::parse_document(body);
+    if has_logon_form(&document) {
+        return true;
+    }
+    if document
+        .select(&Selector::parse(SSR_CLIENT_FORM_SELECTOR).unwrap())
+        .next()
+        .is_some()
+    {
+        return false;
+    }
+
+    contains_any(&visible_text(&document), &SESSION_EXPIRED_MARKERS)
+}
+
+/// 응답 본문이 권한 부족으로 애플리케이션 접근이 거부되었음을 나타내는지 확인합니다.
+/// 스크립트나 `lsdata` 속성이 아닌, 화면에 표시되는 SAP 오류 메시지만 확인합니다.
+pub fn is_authorization_denied(body: &str) -> bool {
+    matches_any(&page_text(body), &AUTHORIZATION_DENIED_PATTERNS)
+}
+
+/// 응답 본문이 요청한 애플리케이션이 존재하지 않음을 나타내는지 확인합니다.
+/// 스크립트나 `lsdata` 속성이 아닌, 화면에 표시되는 SAP 오류 메시지만 확인합니다.
+pub fn is_application_not_found(body: &str) -> bool {
+    matches_any(&page_text(body), &APPLICATION_NOT_FOUND_PATTERNS)
+}
+
+/// 디버깅을 위해 응답 본문의 앞부분을 공백을 줄여 잘라냅니다.
+pub fn snippet(body: &str) -> String {
+    body.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(SNIPPET_LENGTH)
+        .collect()
+}
+
+fn contains_any(body: &str, markers: &[&str]) -> bool {
+    let lowercase_body = body.to_lowercase();
+    markers.iter().any(|marker| lowercase_body.contains(marker))
+}
+
+/// 대소문자를 구분하지 않고 패턴 중 하나라도 일치하는지 확인합니다.
+fn matches_any(text: &str, patterns: &[&str]) -> bool {
+    patterns.iter().any(|pattern| {
+        Regex::new(&format!("(?i){}", pattern))
+            .unwrap()
+            .is_match(text)
+    })
+}
+
+/// 델타 응답이 아닌 페이지의 화면에 표시되는 텍스트를 공백을 줄여 반환합니다.
+fn page_text(body: &str) -> String {
+    if is_updates_document(body) {
+        return String::new();
+    }
+
+    visible_text(&Html::parse_document(body))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn has_logon_form(document: &Html) -> bool {
+    document
+        .select(&Selector::parse(LOGON_FORM_SELECTOR).unwrap())
+        .next()
+        .is_some()
+}
+
+/// 스크립트와 스타일을 제외하고 화면에 표시되는 텍스트만 이어 붙입니다.
+fn visible_text(document: &Html) -> String {
+    document
+        .root_element()
+        .descendants()
+        .filter_map(|node| match node.value() {
+            Node::Text(text) => Some((node, text)),
+            _ => None,
+        })
+        .filter(|(node, _)| {
+            node.parent()
+                .and_then(|parent| parent.value().as_element().map(|element| element.name()))
+                .is_none_or(|name| !matches!(name, "script" | "style"))
+        })
+        .map(|(_, text)| &**text)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
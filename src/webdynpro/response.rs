@@ -0,0 +1,200 @@
+use std::{borrow::Cow, str::FromStr};
+
+use roxmltree::{Document, Node};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ResponseError {
+    #[error("WebDynpro 응답의 XML 형식이 올바르지 않습니다: {0}")]
+    InvalidXml(#[from] roxmltree::Error),
+    #[error("<{element}> 요소에 {attribute} 속성이 없습니다.")]
+    MissingAttribute {
+        element: String,
+        attribute: &'static str,
+    },
+}
+
+/// 응답 본문이 HTML 페이지가 아닌 `<updates>` XML 문서로 시작하는지 확인합니다.
+pub(crate) fn is_updates_document(body: &str) -> bool {
+    let trimmed = body.trim_start_matches('\u{feff}').trim_start();
+    trimmed.starts_with("<?xml") || trimmed.starts_with("<updates")
+}
+
+/// 델타 응답에 포함된 개별 업데이트
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Update {
+    /// `<full-update>`의 `<content-update>`: 창(window) 영역 전체를 새 HTML로 교체합니다.
+    Content {
+        window_id: Option<String>,
+        id: String,
+        html: String,
+    },
+    /// `<delta-update>`의 `<control-update>`: 주어진 ID의 컨트롤만 새 HTML로 교체합니다.
+    Control {
+        window_id: Option<String>,
+        id: String,
+        html: String,
+    },
+    /// 클라이언트에서 실행할 스크립트
+    ScriptCall(String),
+    /// 클라이언트 측 데이터 모델 변경
+    Model {
+        model: Option<String>,
+        attributes: Vec<(String, String)>,
+        content: String,
+    },
+    /// 새로 초기화해야 하는 컨트롤 ID 목록
+    InitializeIds(String),
+    /// 다른 URL로 이동하라는 지시
+    Redirect { url: String },
+}
+
+/// WebDynpro 서버의 이벤트 응답
+/// `ResponseData: delta`로 요청하면 `<updates>` XML로, 그렇지 않으면 전체 HTML 페이지로 응답합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebDynproResponse {
+    /// 전체 HTML 페이지
+    Page(String),
+    /// `<updates>` 델타 응답
+    Updates(Vec<Update>),
+}
+
+impl WebDynproResponse {
+    /// 응답 본문을 해석합니다. `<updates>` 문서가 아니면 전체 페이지로 취급합니다.
+    pub fn parse(body: &str) -> Result<Self, ResponseError> {
+        if !is_updates_document(body) {
+            return Ok(WebDynproResponse::Page(body.to_string()));
+        }
+        let trimmed = body.trim_start_matches('\u{feff}').trim_start();
+
+        let document = Document::parse(trimmed)?;
+        let root = document.root_element();
+        if !root.has_tag_name("updates") {
+            return Ok(WebDynproResponse::Page(body.to_string()));
+        }
+
+        let mut updates = Vec::new();
+        collect_updates(root, None, &mut updates)?;
+
+        Ok(WebDynproResponse::Updates(updates))
+    }
+
+    /// 전체 페이지 응답인지 확인합니다.
+    pub fn is_full_page(&self) -> bool {
+        matches!(self, WebDynproResponse::Page(_))
+    }
+
+    /// 델타 응답의 업데이트 목록을 반환합니다. 전체 페이지 응답이면 빈 목록을 반환합니다.
+    pub fn updates(&self) -> &[Update] {
+        match self {
+            WebDynproResponse::Page(_) => &[],
+            WebDynproResponse::Updates(updates) => updates,
+        }
+    }
+
+    /// 교체된 영역과 컨트롤의 (ID, HTML) 쌍을 응답 순서대로 반환합니다.
+    pub fn fragments(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.updates().iter().filter_map(|update| match update {
+            Update::Content { id, html, .. } | Update::Control { id, html, .. } => {
+                Some((id.as_str(), html.as_str()))
+            }
+            _ => None,
+        })
+    }
+
+    /// 응답에 포함된 스크립트 목록을 반환합니다.
+    pub fn script_calls(&self) -> impl Iterator<Item = &str> {
+        self.updates().iter().filter_map(|update| match update {
+            Update::ScriptCall(script) => Some(script.as_str()),
+            _ => None,
+        })
+    }
+
+    /// 응답이 다른 URL로 이동하라고 지시하면 그 URL을 반환합니다.
+    pub fn redirect_url(&self) -> Option<&str> {
+        self.updates().iter().find_map(|update| match update {
+            Update::Redirect { url } => Some(url.as_str()),
+            _ => None,
+        })
+    }
+
+    /// 응답에 포함된 HTML을 반환합니다.
+    /// 전체 페이지는 그대로, 델타 응답은 교체된 영역과 컨트롤의 HTML을 이어 붙여 반환합니다.
+    pub fn html(&self) -> Cow<'_, str> {
+        match self {
+            WebDynproResponse::Page(body) => Cow::Borrowed(body),
+            WebDynproResponse::Updates(_) => {
+                Cow::Owned(self.fragments().map(|(_, html)| html).collect())
+            }
+        }
+    }
+}
+
+impl FromStr for WebDynproResponse {
+    type Err = ResponseError;
+
+    fn from_str(body: &str) -> Result<Self, Self::Err> {
+        Self::parse(body)
+    }
+}
+
+/// `<updates>`와 그 안의 `<full-update>`, `<delta-update>`를 순회하며 업데이트를 모읍니다.
+fn collect_updates(
+    node: Node,
+    window_id: Option<&str>,
+    updates: &mut Vec<Update>,
+) -> Result<(), ResponseError> {
+    for child in node.children().filter(Node::is_element) {
+        match child.tag_name().name() {
+            "full-update" | "delta-update" => {
+                collect_updates(child, child.attribute("windowid"), updates)?
+            }
+            "content-update" => updates.push(Update::Content {
+                window_id: window_id.map(str::to_string),
+                id: required_attribute(child, "id")?,
+                html: inner_text(child),
+            }),
+            "control-update" => updates.push(Update::Control {
+                window_id: window_id.map(str::to_string),
+                id: required_attribute(child, "id")?,
+                html: inner_text(child),
+            }),
+            "script-call" => updates.push(Update::ScriptCall(inner_text(child))),
+            "model-update" => updates.push(Update::Model {
+                model: child.attribute("model").map(str::to_string),
+                attributes: child
+                    .attributes()
+                    .map(|attribute| (attribute.name().to_string(), attribute.value().to_string()))
+                    .collect(),
+                content: inner_text(child),
+            }),
+            "initialize-ids" => updates.push(Update::InitializeIds(inner_text(child))),
+            "redirect" => updates.push(Update::Redirect {
+                url: required_attribute(child, "url")?,
+            }),
+            // 애니메이션 등 화면 상태와 관계없는 업데이트는 무시
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn required_attribute(node: Node, attribute: &'static str) -> Result<String, ResponseError> {
+    node.attribute(attribute)
+        .map(str::to_string)
+        .ok_or_else(|| ResponseError::MissingAttribute {
+            element: node.tag_name().name().to_string(),
+            attribute,
+        })
+}
+
+/// 요소 안의 텍스트(CDATA 포함)를 모두 이어 붙여 반환합니다.
+fn inner_text(node: Node) -> String {
+    node.descendants()
+        .filter(Node::is_text)
+        .filter_map(|text| text.text())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
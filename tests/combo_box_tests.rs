@@ -0,0 +1,123 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::{
+    applications::course_grades::{
+        model::SemesterType, CourseGradesApplication, CourseGradesApplicationError,
+    },
+    webdynpro::element::{combo_box::ComboBox, Element},
+};
+use reqwest::Client;
+use scraper::Html;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const YEAR_COMBO_BOX_ID: &str = "ZCMW_PERIOD_RE.ID_0DC742680F42DA9747594D1AE51A0C69:VIW_MAIN.PERYR";
+const SEMESTER_COMBO_BOX_ID: &str =
+    "ZCMW_PERIOD_RE.ID_0DC742680F42DA9747594D1AE51A0C69:VIW_MAIN.PERID";
+
+fn combo_boxes() -> String {
+    format!(
+        concat!(
+            r#"<input id="{0}" ct="CB" lsdata="{{2:'YEAR-LB',3:'2024',4:'2024학년도'}}" value="2024학년도">"#,
+            r#"<div id="YEAR-LB" ct="LIB_P">"#,
+            r#"<div data-itemkey=" 2023 " data-itemvalue1="2023학년도"></div>"#,
+            r#"<div data-itemkey="2024" data-itemvalue1="2024학년도"></div>"#,
+            r#"</div>"#,
+            r#"<input id="{1}" ct="CB" lsdata="{{2:'SEMESTER-LB',3:'090'}}">"#,
+            r#"<div id="SEMESTER-LB" ct="LIB_P">"#,
+            r#"<div data-itemkey="090"><span>1 학기</span></div>"#,
+            r#"<div data-itemkey="092"><span>2 학기</span></div>"#,
+            r#"</div>"#,
+        ),
+        YEAR_COMBO_BOX_ID, SEMESTER_COMBO_BOX_ID
+    )
+}
+
+#[test]
+fn test_combo_box_items() {
+    let document = Html::parse_document(&format!("<html><body>{}</body></html>", combo_boxes()));
+
+    let year = ComboBox::find(&document, YEAR_COMBO_BOX_ID).unwrap();
+    assert_eq!(year.item_list_box_id(), Some("YEAR-LB"));
+    assert_eq!(year.items().len(), 2);
+    // 키의 앞뒤 공백은 제거
+    assert_eq!(year.items()[0].key, "2023");
+    assert!(year.item("2023").is_some());
+    assert_eq!(year.item("2024").unwrap().text, "2024학년도");
+    assert!(year.item("2030").is_none());
+
+    // data-itemvalue1이 없으면 항목의 텍스트를 사용
+    let semester = ComboBox::find(&document, SEMESTER_COMBO_BOX_ID).unwrap();
+    assert_eq!(semester.item("092").unwrap().text, "2 학기");
+}
+
+#[tokio::test]
+async fn test_available_periods_and_rejected_selection() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(common::ssr_page("ZCMB3W0017", &combo_boxes())),
+        )
+        .mount(&server)
+        .await;
+
+    let application =
+        CourseGradesApplication::with_config(Arc::new(Client::new()), common::mock_config(&server))
+            .await
+            .unwrap();
+
+    assert_eq!(application.available_years().await.unwrap(), [2023, 2024]);
+    assert_eq!(
+        application.available_semesters().await.unwrap(),
+        [SemesterType::FirstSemester, SemesterType::SecondSemester]
+    );
+
+    // 목록에 없는 학년도와 학기는 이벤트를 보내기 전에 거부
+    assert!(matches!(
+        application
+            .get_semester_grades_details(2030, SemesterType::FirstSemester, false)
+            .await,
+        Err(CourseGradesApplicationError::UnavailableYear(2030))
+    ));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_selection_without_list_box_items() {
+    let server = MockServer::start().await;
+    // 콤보 박스가 참조하는 ListBox가 페이지에 없음
+    let combo_boxes = format!(
+        concat!(
+            r#"<input id="{0}" ct="CB" lsdata="{{2:'YEAR-LB',3:'2024'}}">"#,
+            r#"<input id="{1}" ct="CB" lsdata="{{2:'SEMESTER-LB',3:'090'}}">"#,
+        ),
+        YEAR_COMBO_BOX_ID, SEMESTER_COMBO_BOX_ID
+    );
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(common::ssr_page("ZCMB3W0017", &combo_boxes)),
+        )
+        .mount(&server)
+        .await;
+
+    let application =
+        CourseGradesApplication::with_config(Arc::new(Client::new()), common::mock_config(&server))
+            .await
+            .unwrap();
+
+    assert!(matches!(
+        application
+            .get_semester_grades_details(2024, SemesterType::FirstSemester, false)
+            .await,
+        Err(CourseGradesApplicationError::MissingComboBoxItems(id)) if id == YEAR_COMBO_BOX_ID
+    ));
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use mini_rusaint::config::{USaintConfig, USaintConfigBuilder};
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+pub const LOGIN_PATH: &str = "/sap/bc/webdynpro/sap";
+pub const COURSE_GRADES_PATH: &str = "/sap/bc/webdynpro/SAP/ZCMB3W0017";
+
+/// 로컬 목 서버를 가리키는 설정을 만듭니다.
+pub fn mock_config(server: &MockServer) -> USaintConfig {
+    USaintConfigBuilder::default()
+        .login_url(format!("{}{}", server.uri(), LOGIN_PATH))
+        .webdynpro_base_url(server.uri())
+        .build()
+        .unwrap()
+}
+
+/// SSR 클라이언트 폼이 포함된 WebDynpro 첫 페이지
+pub fn ssr_page(app_name: &str, body: &str) -> String {
+    format!(
+        concat!(
+            r#"<html><body><form id="sap.client.SsrClient.form" name="sap.client.SsrClient.form" method="post" action="/sap/bc/webdynpro/SAP/{0}?sap-contextid=SID%3aANON">"#,
+            r#"<input type="hidden" name="sap-charset" value="utf-8">"#,
+            r#"<input type="hidden" name="sap-wd-secure-id" value="SECURE-ID">"#,
+            r#"<input type="hidden" name="fesrAppName" value="{0}">"#,
+            r#"<input type="hidden" name="fesrUseBeacon" value="true">"#,
+            r#"</form>{1}</body></html>"#
+        ),
+        app_name, body
+    )
+}
+
+/// 학기별 성적 요약 테이블
+pub fn semester_grades_table() -> String {
+    concat!(
+        r#"<table id="ZCMB3W0017.ID_0001:VIW_MAIN.TABLE" ct="ST" lsdata="{2:1,3:14}">"#,
+        r#"<tbody id="ZCMB3W0017.ID_0001:VIW_MAIN.TABLE-contentTBody">"#,
+        r#"<tr rr="0"><th></th><th>학년도</th><th>학기</th><th>신청학점</th><th>취득학점</th><th>P/F학점</th><th>평점평균</th><th>평점계</th><th>산술평균</th><th>학기별석차</th><th>전체석차</th><th>학사경고</th><th>상담여부</th><th>유급</th></tr>"#,
+        r#"<tr rr="1"><td></td><td>2024</td><td>2 학기</td><td>18</td><td>18</td><td>0</td><td>4.2</td><td>75.6</td><td>95.0</td><td>3/50</td><td>10/200</td><td>N</td><td>N</td><td>N</td></tr>"#,
+        r#"</tbody></table>"#
+    )
+    .to_string()
+}
+
+/// SAP 로그인 폼이 MYSAPSSO2 쿠키를 발급하도록 설정합니다.
+pub async fn mount_login(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(LOGIN_PATH))
+        .and(body_string_contains("sap-user"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Set-Cookie", "MYSAPSSO2=TICKET; Path=/")
+                .set_body_string("<html><body>logged in</body></html>"),
+        )
+        .mount(server)
+        .await;
+}
+
+/// 성적 조회 애플리케이션 첫 페이지와 이벤트 응답을 설정합니다.
+pub async fn mount_course_grades(server: &MockServer) {
+    let page = ssr_page("ZCMB3W0017", &semester_grades_table());
+
+    Mock::given(method("GET"))
+        .and(path(COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page.clone()))
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(COURSE_GRADES_PATH))
+        .and(body_string_contains("sap-wd-secure-id=SECURE-ID"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page))
+        .mount(server)
+        .await;
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::{
+    applications::course_grades::CourseGradesApplication,
+    config::USaintConfig,
+    session::{credentials::Credentials, credentials::FnCredentialProvider, USaintSession},
+};
+use wiremock::MockServer;
+
+#[test]
+fn test_default_config_urls() {
+    let config = USaintConfig::default();
+
+    assert_eq!(
+        config.application_url("ZCMB3W0017"),
+        "https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB3W0017?sap-wd-stableids=x"
+    );
+    assert_eq!(
+        config.action_url("/sap/bc/webdynpro/SAP/ZCMB3W0017?sap-contextid=1"),
+        "https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB3W0017?sap-contextid=1"
+    );
+}
+
+#[tokio::test]
+async fn test_session_against_mock_server() {
+    let server = MockServer::start().await;
+    common::mount_login(&server).await;
+    common::mount_course_grades(&server).await;
+
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    let session = USaintSession::with_config(&provider, common::mock_config(&server))
+        .await
+        .unwrap();
+
+    let course_grades_app = CourseGradesApplication::with_session(Arc::new(session))
+        .await
+        .unwrap();
+    let semester_grades = course_grades_app.get_all_semester_grades().await.unwrap();
+
+    assert_eq!(semester_grades.len(), 1);
+    assert_eq!(semester_grades[0].year, 2024);
+    assert_eq!(semester_grades[0].semester_rank, (3, 50));
+}
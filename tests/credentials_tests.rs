@@ -0,0 +1,50 @@
+use mini_rusaint::session::credentials::{
+    CredentialError, CredentialProvider, Credentials, EncryptedFileCredentialProvider,
+    FnCredentialProvider,
+};
+
+#[test]
+fn test_credentials_debug_is_redacted() {
+    let credentials = Credentials::new("20201234", "secret-password");
+    let debug = format!("{:?}", credentials);
+
+    assert!(debug.contains("20201234"));
+    assert!(!debug.contains("secret-password"));
+}
+
+#[test]
+fn test_fn_credential_provider() {
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    let credentials = provider.credentials().unwrap();
+
+    assert_eq!(credentials.id(), "20201234");
+    assert_eq!(credentials.password(), "password");
+}
+
+#[test]
+fn test_encrypted_file_credential_provider() {
+    let path = std::env::temp_dir().join(format!(
+        "mini-rusaint-credentials-{}.json",
+        std::process::id()
+    ));
+
+    let provider = EncryptedFileCredentialProvider::new(&path, "passphrase");
+    provider
+        .write(&Credentials::new("20201234", "password"))
+        .unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("password\""));
+
+    let credentials = provider.credentials().unwrap();
+    assert_eq!(credentials.id(), "20201234");
+    assert_eq!(credentials.password(), "password");
+
+    let wrong_provider = EncryptedFileCredentialProvider::new(&path, "wrong passphrase");
+    assert!(matches!(
+        wrong_provider.credentials(),
+        Err(CredentialError::DecryptionFailed)
+    ));
+
+    std::fs::remove_file(&path).unwrap();
+}
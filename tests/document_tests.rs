@@ -0,0 +1,194 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::{
+    applications::Application,
+    webdynpro::{
+        document::{DocumentError, WebDynproDocument},
+        response::WebDynproResponse,
+    },
+};
+use reqwest::Client;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const APP_NAME: &str = "ZCMB3W0017";
+
+fn control_update(id: &str, html: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><updates><delta-update windowid="main"><control-update id="{}"><content><![CDATA[{}]]></content></control-update></delta-update></updates>"#,
+        id, html
+    )
+}
+
+#[test]
+fn test_apply_delta_updates() {
+    let mut document = WebDynproDocument::new(
+        r#"<html><body><div id="ROOT"><span id="TEXT">old</span><span id="OTHER">keep</span></div></body></html>"#,
+    );
+
+    let response =
+        WebDynproResponse::parse(&control_update("TEXT", r#"<span id="TEXT">new</span>"#)).unwrap();
+    document.apply(&response).unwrap();
+
+    assert_eq!(
+        document.control_html("TEXT").as_deref(),
+        Some(r#"<span id="TEXT">new</span>"#)
+    );
+    assert!(document.contains("OTHER"));
+
+    // 문서에 없는 컨트롤을 가리키는 업데이트는 오류이며, 같은 응답의 다른 업데이트도 적용하지 않음
+    let response = WebDynproResponse::parse(concat!(
+        r#"<updates><delta-update windowid="main">"#,
+        r#"<control-update id="OTHER"><content><![CDATA[<span id="OTHER">changed</span>]]></content></control-update>"#,
+        r#"<control-update id="POPUP"><content><![CDATA[<div id="POPUP">popup</div>]]></content></control-update>"#,
+        r#"</delta-update></updates>"#
+    ))
+    .unwrap();
+    assert!(matches!(
+        document.apply(&response),
+        Err(DocumentError::MissingTarget(id)) if id == "POPUP"
+    ));
+    assert!(!document.contains("POPUP"));
+    assert_eq!(
+        document.control_html("OTHER").as_deref(),
+        Some(r#"<span id="OTHER">keep</span>"#)
+    );
+
+    // 영역 전체 교체
+    let response = WebDynproResponse::parse(
+        r#"<updates><full-update><content-update id="ROOT"><![CDATA[<p id="FRESH">fresh</p>]]></content-update></full-update></updates>"#,
+    )
+    .unwrap();
+    document.apply(&response).unwrap();
+    assert!(document.contains("FRESH"));
+    assert!(document.contains("ROOT"));
+    assert!(!document.contains("TEXT"));
+
+    // 전체 페이지 응답은 문서를 교체
+    document
+        .apply(&WebDynproResponse::Page(
+            "<html><body>page</body></html>".to_string(),
+        ))
+        .unwrap();
+    assert!(!document.contains("ROOT"));
+}
+
+#[test]
+fn test_apply_update_with_special_id() {
+    let mut document =
+        WebDynproDocument::new(r#"<html><body><span id="A\B">old</span></body></html>"#);
+
+    // 역슬래시가 들어간 ID도 선택자 오류로 패닉하지 않고 찾음
+    let response =
+        WebDynproResponse::parse(&control_update(r"A\B", r#"<span id="A\B">new</span>"#)).unwrap();
+    document.apply(&response).unwrap();
+    assert!(document.html().contains("new"));
+    assert!(!document.contains("A\n"));
+}
+
+#[test]
+fn test_apply_table_row_updates() {
+    let mut document = WebDynproDocument::new(concat!(
+        r#"<html><body><table id="TABLE" ct="ST"><tbody id="TABLE-contentTBody">"#,
+        r#"<tr id="ROW1" rr="1"><td>1</td><td>old</td></tr>"#,
+        r#"</tbody></table></body></html>"#
+    ));
+
+    // 테이블 본문의 행 목록 교체
+    let response = WebDynproResponse::parse(
+        r#"<updates><full-update><content-update id="TABLE-contentTBody"><![CDATA[<tr id="ROW1" rr="1"><td>1</td><td>first</td></tr><tr id="ROW2" rr="2"><td>2</td><td>second</td></tr>]]></content-update></full-update></updates>"#,
+    )
+    .unwrap();
+    document.apply(&response).unwrap();
+    assert_eq!(
+        document.control_html("ROW2").as_deref(),
+        Some(r#"<tr id="ROW2" rr="2"><td>2</td><td>second</td></tr>"#)
+    );
+
+    // 행 하나만 교체
+    let response = WebDynproResponse::parse(&control_update(
+        "ROW1",
+        r#"<tr id="ROW1" rr="1"><td>1</td><td>updated</td></tr>"#,
+    ))
+    .unwrap();
+    document.apply(&response).unwrap();
+    assert_eq!(
+        document.control_html("TABLE-contentTBody").as_deref(),
+        Some(concat!(
+            r#"<tbody id="TABLE-contentTBody">"#,
+            r#"<tr id="ROW1" rr="1"><td>1</td><td>updated</td></tr>"#,
+            r#"<tr id="ROW2" rr="2"><td>2</td><td>second</td></tr>"#,
+            r#"</tbody>"#
+        ))
+    );
+
+    // 행 안의 셀 목록 교체
+    let response = WebDynproResponse::parse(
+        r#"<updates><full-update><content-update id="ROW2"><![CDATA[<td>2</td><td>cell</td>]]></content-update></full-update></updates>"#,
+    )
+    .unwrap();
+    document.apply(&response).unwrap();
+    assert_eq!(
+        document.control_html("ROW2").as_deref(),
+        Some(r#"<tr id="ROW2" rr="2"><td>2</td><td>cell</td></tr>"#)
+    );
+}
+
+#[tokio::test]
+async fn test_application_keeps_document_between_requests() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(common::ssr_page(
+            APP_NAME,
+            r#"<span id="A">a0</span><span id="B">b0</span>"#,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .and(body_string_contains("first"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(control_update("A", r#"<span id="A">a1</span>"#)),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .and(body_string_contains("second"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(control_update("B", r#"<span id="B">b1</span>"#)),
+        )
+        .mount(&server)
+        .await;
+
+    let application = Application::with_config(
+        Arc::new(Client::new()),
+        common::mock_config(&server),
+        APP_NAME,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        application.control_html("A").await.as_deref(),
+        Some(r#"<span id="A">a0</span>"#)
+    );
+
+    application.send_request(Some("first")).await.unwrap();
+    application.send_request(Some("second")).await.unwrap();
+
+    assert_eq!(
+        application.control_html("A").await.as_deref(),
+        Some(r#"<span id="A">a1</span>"#)
+    );
+    assert_eq!(
+        application.control_html("B").await.as_deref(),
+        Some(r#"<span id="B">b1</span>"#)
+    );
+}
@@ -0,0 +1,111 @@
+use mini_rusaint::webdynpro::{
+    element::{
+        button::Button, check_box::CheckBox, combo_box::ComboBox, input_field::InputField,
+        popup_window::PopupWindow, sap_table::SapTable, text_view::TextView, Element, ElementError,
+    },
+    event::SapEventParameters,
+};
+use scraper::Html;
+
+const PAGE: &str = r#"<html><body>
+<div id="BTN" ct="B" aria-disabled="false"><span>조회</span></div>
+<input id="YEAR" ct="CB" value="2024학년도">
+<input id="NAME" ct="I" value="홍길동" readonly>
+<span id="AGREE" ct="C" aria-checked="true">동의</span>
+<span id="TEXT" ct="TV">  학기별   성적 </span>
+<div id="TABLE" ct="ST"></div>
+<div id="POPUP" ct="PW"><span class="lsPopupWindow__title">상세 성적</span></div>
+</body></html>"#;
+
+fn parameter<'a>(parameters: &'a SapEventParameters, key: &str) -> &'a str {
+    parameters.get(key).map(String::as_str).unwrap_or_default()
+}
+
+#[test]
+fn test_find_elements() {
+    let document = Html::parse_document(PAGE);
+
+    let button = Button::find(&document, "BTN").unwrap();
+    assert_eq!(button.text(), "조회");
+    assert!(button.is_enabled());
+
+    assert_eq!(
+        ComboBox::find(&document, "YEAR").unwrap().value(),
+        "2024학년도"
+    );
+    assert!(InputField::find(&document, "NAME").unwrap().is_read_only());
+    assert!(CheckBox::find(&document, "AGREE").unwrap().is_checked());
+    assert_eq!(
+        TextView::find(&document, "TEXT").unwrap().text(),
+        "학기별 성적"
+    );
+    assert_eq!(
+        PopupWindow::find(&document, "POPUP").unwrap().title(),
+        "상세 성적"
+    );
+
+    assert!(matches!(
+        Button::find(&document, "MISSING"),
+        Err(ElementError::NotFound(_))
+    ));
+    assert!(matches!(
+        Button::find(&document, "YEAR"),
+        Err(ElementError::UnexpectedControl { found, .. }) if found == "CB"
+    ));
+}
+
+#[test]
+fn test_find_elements_with_special_ids() {
+    // 역슬래시, 따옴표, 줄바꿈이 들어간 ID도 선택자 오류 없이 찾음
+    let document = Html::parse_document(concat!(
+        r#"<div id="A\B" ct="B"><span>역슬래시</span></div>"#,
+        r#"<div id="A&quot;B" ct="B"><span>따옴표</span></div>"#,
+        "<div id=\"A\nB\" ct=\"B\"><span>줄바꿈</span></div>",
+    ));
+
+    assert_eq!(Button::find(&document, r"A\B").unwrap().text(), "역슬래시");
+    assert_eq!(Button::find(&document, r#"A"B"#).unwrap().text(), "따옴표");
+    assert_eq!(Button::find(&document, "A\nB").unwrap().text(), "줄바꿈");
+    assert!(matches!(
+        Button::find(&document, "A\\"),
+        Err(ElementError::NotFound(_))
+    ));
+}
+
+#[test]
+fn test_element_events() {
+    let document = Html::parse_document(PAGE);
+
+    let press = Button::find(&document, "BTN").unwrap().press();
+    assert_eq!(press.event, "Button");
+    assert_eq!(press.control, "Press");
+    assert_eq!(parameter(&press.parameters, "Id"), "BTN");
+    assert_eq!(parameter(&press.ucf_parameters, "ClientAction"), "submit");
+    assert_eq!(parameter(&press.ucf_parameters, "ResponseData"), "delta");
+
+    let select = ComboBox::find(&document, "YEAR").unwrap().select(2024);
+    assert_eq!(
+        select.to_string().split('~').next(),
+        Some("ComboBox_Select")
+    );
+    assert_eq!(parameter(&select.parameters, "Key"), "2024");
+    assert_eq!(parameter(&select.parameters, "ByEnter"), "false");
+
+    let change = InputField::find(&document, "NAME")
+        .unwrap()
+        .change("이몽룡");
+    assert_eq!(parameter(&change.parameters, "Value"), "이몽룡");
+    assert_eq!(parameter(&change.ucf_parameters, "ClientAction"), "enqueue");
+
+    let toggle = CheckBox::find(&document, "AGREE").unwrap().toggle(false);
+    assert_eq!(parameter(&toggle.parameters, "Checked"), "false");
+
+    let row_select = SapTable::find(&document, "TABLE").unwrap().row_select(3);
+    assert_eq!(row_select.control, "RowSelect");
+    assert_eq!(parameter(&row_select.parameters, "RowIndex"), "3");
+
+    let close = PopupWindow::find(&document, "POPUP").unwrap().close();
+    assert!(close
+        .to_string()
+        .starts_with("PopupWindow_Close~E002Id~E004POPUP~E003"));
+}
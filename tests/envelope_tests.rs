@@ -0,0 +1,85 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::{
+    applications::Application,
+    webdynpro::{element::button::Button, event::SapEventQueue},
+};
+use reqwest::Client;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const APP_NAME: &str = "ZCMB3W0017";
+const BUTTON: &str = r#"<div id="BTN" ct="B"><span>조회</span></div>"#;
+
+async fn application(server: &MockServer) -> Application {
+    let page = common::ssr_page(APP_NAME, BUTTON);
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page.clone()))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page))
+        .mount(server)
+        .await;
+
+    Application::with_config(
+        Arc::new(Client::new()),
+        common::mock_config(server),
+        APP_NAME,
+    )
+    .await
+    .unwrap()
+}
+
+/// 마지막으로 받은 요청의 폼 본문을 쿼리 문자열로 해석해 (이름, 값) 쌍으로 반환합니다.
+async fn last_form(server: &MockServer) -> Vec<(String, String)> {
+    let requests = server.received_requests().await.unwrap();
+    let body = String::from_utf8(requests.last().unwrap().body.clone()).unwrap();
+    reqwest::Url::parse(&format!("http://localhost/?{}", body))
+        .unwrap()
+        .query_pairs()
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect()
+}
+
+fn event_queue(form: &[(String, String)]) -> SapEventQueue {
+    form.iter()
+        .find(|(key, _)| key == "SAPEVENTQUEUE")
+        .map(|(_, value)| value.parse().unwrap())
+        .unwrap()
+}
+
+fn event_names(queue: &SapEventQueue) -> Vec<String> {
+    queue
+        .events()
+        .map(|event| format!("{}_{}", event.event, event.control))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_send_event_wraps_user_event() {
+    let server = MockServer::start().await;
+    let application = application(&server).await;
+    let button = application.find_element::<Button>("BTN").await.unwrap();
+    application.send_event(button.press()).await.unwrap();
+
+    let queue = event_queue(&last_form(&server).await);
+
+    // 애플리케이션 코드는 버튼 이벤트만 만들고, 앞뒤 이벤트는 자동으로 붙음
+    let names = event_names(&queue);
+    assert_eq!(
+        names,
+        ["ClientInspector_Notify", "Button_Press", "Form_Request"]
+    );
+    let form_request = queue.events().last().unwrap();
+    assert_eq!(
+        form_request.parameters.get("FocusInfo").unwrap(),
+        r#"@{"sFocussedId":"BTN"}"#
+    );
+}
@@ -1,4 +1,7 @@
-use mini_rusaint::webdynpro::event::{decode_sap_event, SapEventBuilder};
+use mini_rusaint::webdynpro::event::{
+    decode_sap_event, SapEvent, SapEventBuilder, SapEventParseError, SapEventQueue,
+    SapEventQueueBuilder,
+};
 
 #[test]
 fn test_sap_event_decode() {
@@ -44,3 +47,216 @@ fn test_sap_event_builder() {
     assert!(sap_event_str.contains("ClientAction~E004submit"));
     assert!(sap_event_str.contains("~E002~E003"));
 }
+
+#[test]
+fn test_sap_event_queue_matches_browser() {
+    // 브라우저에서 캡처한 SAPEVENTQUEUE
+    let captured = "ClientInspector_Notify~E002Id~E004WD01~E005Data~E004CssMatchesHtmlVersion~003ATRUE~E003~E002ResponseData~E004delta~E005EnqueueCardinality~E004single~E003~E002~E003~E001Button_Press~E002Id~E004ZCMB3W0017.ID_0001~003AW_POPUP.WDBUTTON_5~E003~E002ResponseData~E004delta~E005ClientAction~E004submit~E003~E002~E003~E001Form_Request~E002Id~E004sap.client.SsrClient.form~E005Async~E004false~E005FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022ZCMB3W0017.ID_0001~003AW_POPUP.WDBUTTON_5~0022~007D~E005Hash~E004~E005DomChanged~E004false~E005IsDirty~E004false~E003~E002ResponseData~E004delta~E003~E002~E003";
+
+    let queue = SapEventQueueBuilder::default()
+        .add_event(
+            SapEventBuilder::default()
+                .event("ClientInspector")
+                .control("Notify")
+                .add_parameter(("Id".to_string(), "WD01".to_string()))
+                .add_parameter(("Data".to_string(), "CssMatchesHtmlVersion:TRUE".to_string()))
+                // 잘 알려진 ucf 키는 추가한 순서와 관계없이 브라우저 순서로 직렬화
+                .add_ucf_parameter(("EnqueueCardinality".to_string(), "single".to_string()))
+                .add_ucf_parameter(("ResponseData".to_string(), "delta".to_string()))
+                .build()
+                .unwrap(),
+        )
+        .add_event(
+            SapEventBuilder::default()
+                .event("Button")
+                .control("Press")
+                .add_parameter((
+                    "Id".to_string(),
+                    "ZCMB3W0017.ID_0001:W_POPUP.WDBUTTON_5".to_string(),
+                ))
+                .add_ucf_parameter(("ClientAction".to_string(), "submit".to_string()))
+                .add_ucf_parameter(("ResponseData".to_string(), "delta".to_string()))
+                .build()
+                .unwrap(),
+        )
+        .add_event(
+            SapEventBuilder::default()
+                .event("Form")
+                .control("Request")
+                .add_parameter(("Id".to_string(), "sap.client.SsrClient.form".to_string()))
+                .add_parameter(("Async".to_string(), "false".to_string()))
+                .add_parameter((
+                    "FocusInfo".to_string(),
+                    r#"@{"sFocussedId":"ZCMB3W0017.ID_0001:W_POPUP.WDBUTTON_5"}"#.to_string(),
+                ))
+                .add_parameter(("Hash".to_string(), "".to_string()))
+                .add_parameter(("DomChanged".to_string(), "false".to_string()))
+                .add_parameter(("IsDirty".to_string(), "false".to_string()))
+                .add_ucf_parameter(("ResponseData".to_string(), "delta".to_string()))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(queue.to_string(), captured);
+}
+
+#[test]
+fn test_sap_event_parameters_keep_insertion_order() {
+    let sap_event = SapEventBuilder::default()
+        .event("SapTable")
+        .control("RowSelect")
+        .add_parameter(("Id".to_string(), "TABLE".to_string()))
+        .add_parameter(("RowIndex".to_string(), "1".to_string()))
+        .add_parameter(("AccessType".to_string(), "STANDARD".to_string()))
+        // 같은 키는 원래 자리의 값을 교체
+        .add_parameter(("RowIndex".to_string(), "2".to_string()))
+        .build()
+        .unwrap();
+
+    let keys: Vec<_> = sap_event.parameters.iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, ["Id", "RowIndex", "AccessType"]);
+    assert_eq!(
+        sap_event.to_string(),
+        "SapTable_RowSelect~E002Id~E004TABLE~E005RowIndex~E0042~E005AccessType~E004STANDARD~E003~E002~E003~E002~E003"
+    );
+}
+
+#[test]
+fn test_parse_sap_event_queue() {
+    let captured = "ClientInspector_Notify~E002Id~E004WD01~E005Data~E004CssMatchesHtmlVersion~003ATRUE~E003~E002ResponseData~E004delta~E005EnqueueCardinality~E004single~E003~E002~E003~E001Button_Press~E002Id~E004ZCMB3W0017.ID_0001~003AW_POPUP.WDBUTTON_5~E003~E002ResponseData~E004delta~E005ClientAction~E004submit~E003~E002~E003~E001Form_Request~E002Id~E004sap.client.SsrClient.form~E005Async~E004false~E005FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022ZCMB3W0017.ID_0001~003AW_POPUP.WDBUTTON_5~0022~007D~E005Hash~E004~E005DomChanged~E004false~E005IsDirty~E004false~E003~E002ResponseData~E004delta~E003~E002~E003";
+
+    let queue: SapEventQueue = captured.parse().unwrap();
+    let events: Vec<_> = queue.events().collect();
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1].event, "Button");
+    assert_eq!(events[1].control, "Press");
+    assert_eq!(
+        events[1].parameters.get("Id").unwrap(),
+        "ZCMB3W0017.ID_0001:W_POPUP.WDBUTTON_5"
+    );
+    assert_eq!(
+        events[2].parameters.get("FocusInfo").unwrap(),
+        r#"@{"sFocussedId":"ZCMB3W0017.ID_0001:W_POPUP.WDBUTTON_5"}"#
+    );
+    assert_eq!(events[2].parameters.get("Hash").unwrap(), "");
+
+    // 인코딩과 해석이 정확히 왕복
+    assert_eq!(queue.to_string(), captured);
+}
+
+#[test]
+fn test_sap_event_round_trip() {
+    let sap_event = SapEventBuilder::default()
+        .event("InputField")
+        .control("Change")
+        .add_parameter(("Id".to_string(), "NAME".to_string()))
+        .add_parameter(("Value".to_string(), "홍길동 ~E001 😀".to_string()))
+        .add_ucf_parameter(("ClientAction".to_string(), "enqueue".to_string()))
+        .add_custom_parameter(("Custom".to_string(), "{a:b}".to_string()))
+        .build()
+        .unwrap();
+
+    let parsed: SapEvent = sap_event.to_string().parse().unwrap();
+
+    assert_eq!(parsed, sap_event);
+}
+
+#[test]
+fn test_sap_event_round_trip_with_reordered_ucf_parameters() {
+    // 브라우저 순서와 다르게 추가된 ucf 파라미터와, 순서 목록에 없는 키
+    let sap_event = SapEventBuilder::default()
+        .event("Button")
+        .control("Press")
+        .add_parameter(("Id".to_string(), "BTN".to_string()))
+        .add_ucf_parameter(("Custom".to_string(), "x".to_string()))
+        .add_ucf_parameter(("ClientAction".to_string(), "submit".to_string()))
+        .add_ucf_parameter(("ResponseData".to_string(), "delta".to_string()))
+        .build()
+        .unwrap();
+
+    let encoded = sap_event.to_string();
+    let parsed: SapEvent = encoded.parse().unwrap();
+
+    assert_eq!(
+        encoded,
+        "Button_Press~E002Id~E004BTN~E003~E002ResponseData~E004delta~E005ClientAction~E004submit~E005Custom~E004x~E003~E002~E003"
+    );
+    assert_eq!(parsed, sap_event);
+    assert_eq!(parsed.to_string(), encoded);
+
+    // 일반 파라미터는 순서도 비교
+    let reordered = SapEventBuilder::default()
+        .event("Button")
+        .control("Press")
+        .add_parameter(("Key".to_string(), "1".to_string()))
+        .add_parameter(("Id".to_string(), "BTN".to_string()))
+        .build()
+        .unwrap();
+    let original: SapEvent = "Button_Press~E002Id~E004BTN~E005Key~E0041~E003~E002~E003~E002~E003"
+        .parse()
+        .unwrap();
+    assert_ne!(original, reordered);
+}
+
+#[test]
+fn test_sap_event_value_wire_format() {
+    let sap_event = SapEventBuilder::default()
+        .event("InputField")
+        .control("Change")
+        .add_parameter(("Value".to_string(), "a~b 😀".to_string()))
+        .build()
+        .unwrap();
+
+    // `~`는 구분자와 헷갈리지 않도록 인코딩하고, BMP 밖의 문자는 UTF-16 서로게이트 쌍으로 나눔
+    assert_eq!(
+        sap_event.to_string(),
+        "InputField_Change~E002Value~E004a~007Eb~0020~D83D~DE00~E003~E002~E003~E002~E003"
+    );
+}
+
+#[test]
+fn test_parse_invalid_sap_event() {
+    assert!(matches!(
+        "Press~E002~E003~E002~E003~E002~E003".parse::<SapEvent>(),
+        Err(SapEventParseError::InvalidEventName(_))
+    ));
+    assert!(matches!(
+        "Button_Press~E002Id~E004BTN~E003".parse::<SapEvent>(),
+        Err(SapEventParseError::InvalidParameterGroups { found: 1, .. })
+    ));
+    assert!(matches!(
+        "Button_Press~E002IdBTN~E003~E002~E003~E002~E003".parse::<SapEvent>(),
+        Err(SapEventParseError::InvalidParameter { .. })
+    ));
+    assert!(matches!(
+        "Button_Press~E002Id~E004~ZZ~E003~E002~E003~E002~E003".parse::<SapEvent>(),
+        Err(SapEventParseError::InvalidEncoding(_))
+    ));
+    assert!(matches!(
+        "Button_Press~E002Id~E004A~E005Id~E004B~E003~E002~E003~E002~E003".parse::<SapEvent>(),
+        Err(SapEventParseError::DuplicateParameter { key, .. }) if key == "Id"
+    ));
+}
+
+#[test]
+fn test_sap_event_queue_with_envelope() {
+    let captured = "ClientInspector_Notify~E002Id~E004WD01~E005Data~E004CssMatchesHtmlVersion~003ATRUE~E003~E002ResponseData~E004delta~E005EnqueueCardinality~E004single~E003~E002~E003~E001Button_Press~E002Id~E004ZCMB3W0017.ID_0001~003AW_POPUP.WDBUTTON_5~E003~E002ResponseData~E004delta~E005ClientAction~E004submit~E003~E002~E003~E001Form_Request~E002Id~E004sap.client.SsrClient.form~E005Async~E004false~E005FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022ZCMB3W0017.ID_0001~003AW_POPUP.WDBUTTON_5~0022~007D~E005Hash~E004~E005DomChanged~E004false~E005IsDirty~E004false~E003~E002ResponseData~E004delta~E003~E002~E003";
+    let press = SapEventBuilder::default()
+        .event("Button")
+        .control("Press")
+        .add_parameter((
+            "Id".to_string(),
+            "ZCMB3W0017.ID_0001:W_POPUP.WDBUTTON_5".to_string(),
+        ))
+        .add_ucf_parameter(("ClientAction".to_string(), "submit".to_string()))
+        .add_ucf_parameter(("ResponseData".to_string(), "delta".to_string()))
+        .build()
+        .unwrap();
+
+    let queue = SapEventQueue::with_envelope([press]);
+
+    assert_eq!(queue.to_string(), captured);
+}
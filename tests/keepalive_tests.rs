@@ -0,0 +1,79 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use mini_rusaint::{
+    applications::course_grades::CourseGradesApplication,
+    session::{
+        credentials::{Credentials, FnCredentialProvider},
+        keepalive::KeepAliveError,
+        USaintSession,
+    },
+};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn mock_session(server: &MockServer) -> Arc<USaintSession> {
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    Arc::new(
+        USaintSession::with_config(&provider, common::mock_config(server))
+            .await
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_keepalive_notifies_open_applications() {
+    let server = MockServer::start().await;
+    common::mount_login(&server).await;
+    common::mount_course_grades(&server).await;
+
+    let session = mock_session(&server).await;
+    let course_grades_app = Arc::new(
+        CourseGradesApplication::with_session(session.clone())
+            .await
+            .unwrap(),
+    );
+
+    let mut keepalive = session.start_keepalive(Duration::from_millis(50));
+    keepalive.add_application(&course_grades_app);
+    tokio::time::sleep(Duration::from_millis(180)).await;
+
+    let notifications = server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| String::from_utf8_lossy(&request.body).contains("ClientInspector_Notify"))
+        .collect::<Vec<_>>();
+    assert!(notifications.len() >= 2);
+    // 이벤트 큐 앞에 붙는 알림과 같은 내용을 보냄
+    assert!(notifications.iter().all(|request| {
+        String::from_utf8_lossy(&request.body).contains("CssMatchesHtmlVersion")
+    }));
+    assert!(keepalive.try_next_failure().is_none());
+}
+
+#[tokio::test]
+async fn test_keepalive_reports_expired_session() {
+    let server = MockServer::start().await;
+    common::mount_login(&server).await;
+    Mock::given(method("GET"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(r#"<form><input type="text" name="sap-user" value=""></form>"#),
+        )
+        .mount(&server)
+        .await;
+
+    let session = mock_session(&server).await;
+    let mut keepalive = session.start_keepalive(Duration::from_millis(20));
+
+    let failure = tokio::time::timeout(Duration::from_secs(1), keepalive.next_failure())
+        .await
+        .unwrap();
+    assert!(matches!(failure, Some(KeepAliveError::SessionExpired)));
+}
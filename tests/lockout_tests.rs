@@ -0,0 +1,253 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use mini_rusaint::{
+    config::USaintConfig,
+    session::{
+        builder::USaintSessionBuilder, lockout::LoginGuard, LoginStrategy, USaintSessionError,
+    },
+};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const WRONG_PASSWORD_PAGE: &str = r#"<form><span class="urMsgBarTxt">Name or password is incorrect (repeat logon)</span><input type="text" name="sap-user" value=""></form>"#;
+
+#[tokio::test]
+async fn test_login_guard_blocks_after_threshold() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(WRONG_PASSWORD_PAGE))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let guard = Arc::new(LoginGuard::new(2));
+    let login = || {
+        USaintSessionBuilder::new()
+            .config(common::mock_config(&server))
+            .login_guard(guard.clone())
+            .with_password("20201234", "wrong")
+    };
+
+    for _ in 0..2 {
+        assert!(matches!(
+            login().await,
+            Err(USaintSessionError::WrongPassword(_))
+        ));
+    }
+
+    // 한도에 도달하면 서버에 요청하지 않고 거부
+    assert!(matches!(
+        login().await,
+        Err(USaintSessionError::TooManyFailedLogins { failures: 2, .. })
+    ));
+    assert_eq!(guard.failures("20201234"), 2);
+    assert_eq!(guard.failures("20209999"), 0);
+
+    guard.reset("20201234").unwrap();
+    assert!(guard.check("20201234").is_ok());
+}
+
+#[tokio::test]
+async fn test_login_guard_resets_on_success() {
+    let server = MockServer::start().await;
+    common::mount_login(&server).await;
+
+    let guard = Arc::new(LoginGuard::new(3));
+    guard.check("20201234").unwrap();
+
+    let session = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_guard(guard.clone())
+        .with_password("20201234", "password")
+        .await;
+
+    assert!(session.is_ok());
+    assert_eq!(guard.failures("20201234"), 0);
+}
+
+#[tokio::test]
+async fn test_login_guard_state_file() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(WRONG_PASSWORD_PAGE))
+        .mount(&server)
+        .await;
+
+    let path = std::env::temp_dir().join(format!(
+        "mini-rusaint-login-guard-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+
+    let guard = Arc::new(LoginGuard::with_state_file(1, &path).unwrap());
+    let result = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_guard(guard)
+        .with_password("20201234", "wrong")
+        .await;
+    assert!(matches!(result, Err(USaintSessionError::WrongPassword(_))));
+
+    // 다른 프로세스에서 같은 파일을 불러와도 실패 기록이 유지됨
+    let restored = LoginGuard::with_state_file(1, &path).unwrap();
+    assert_eq!(restored.failures("20201234"), 1);
+    assert!(matches!(
+        restored.check("20201234"),
+        Err(USaintSessionError::TooManyFailedLogins { .. })
+    ));
+
+    restored.reset("20201234").unwrap();
+    let restored = LoginGuard::with_state_file(1, &path).unwrap();
+    assert_eq!(restored.failures("20201234"), 0);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_login_guard_counts_unrecognized_login_form() {
+    let server = MockServer::start().await;
+    // 알려진 메시지 영역이 아닌 곳에 메시지를 담은 로그인 폼이 다시 내려옴
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<form><p>Logon not possible</p><input type="text" name="sap-user" value=""></form>"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let guard = Arc::new(LoginGuard::new(1));
+    let login = || {
+        USaintSessionBuilder::new()
+            .config(common::mock_config(&server))
+            .login_guard(guard.clone())
+            .with_password("20201234", "wrong")
+    };
+
+    assert!(matches!(
+        login().await,
+        Err(USaintSessionError::MissingMYSAPSSO2Cookie)
+    ));
+    assert_eq!(guard.failures("20201234"), 1);
+    assert!(matches!(
+        login().await,
+        Err(USaintSessionError::TooManyFailedLogins { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_login_guard_limits_concurrent_attempts() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(WRONG_PASSWORD_PAGE)
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let guard = Arc::new(LoginGuard::new(1));
+    let login = || {
+        USaintSessionBuilder::new()
+            .config(common::mock_config(&server))
+            .login_guard(guard.clone())
+            .with_password("20201234", "wrong")
+    };
+
+    // 첫 시도의 결과를 기다리는 동안에는 남은 허용 횟수를 넘는 시도를 보내지 않음
+    let (first, second) = tokio::join!(login(), login());
+    assert!(matches!(first, Err(USaintSessionError::WrongPassword(_))));
+    assert!(matches!(
+        second,
+        Err(USaintSessionError::TooManyFailedLogins { failures: 0, .. })
+    ));
+    assert_eq!(guard.failures("20201234"), 1);
+}
+
+#[tokio::test]
+async fn test_login_guard_save_error_keeps_login_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(WRONG_PASSWORD_PAGE))
+        .mount(&server)
+        .await;
+
+    // 존재하지 않는 디렉터리라 상태 파일을 저장할 수 없음
+    let path = std::env::temp_dir()
+        .join(format!("mini-rusaint-missing-{}", std::process::id()))
+        .join("login-guard.json");
+    let guard = Arc::new(LoginGuard::with_state_file(3, &path).unwrap());
+
+    let result = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_guard(guard.clone())
+        .with_password("20201234", "wrong")
+        .await;
+
+    assert!(matches!(result, Err(USaintSessionError::WrongPassword(_))));
+    assert_eq!(guard.failures("20201234"), 1);
+}
+
+#[tokio::test]
+async fn test_login_guard_counts_smartid_login_without_ticket() {
+    const SMARTID_LOGIN_PATH: &str = "/Symtra_sso/smln_pcs.asp";
+    const PORTAL_SSO_PATH: &str = "/webSSO/sso.jsp";
+
+    let server = MockServer::start().await;
+    // 첫 시도: 메시지 없이 sToken이 발급되지 않음
+    Mock::given(method("POST"))
+        .and(path(SMARTID_LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    // 두 번째 시도: sToken은 발급되지만 포털이 MYSAPSSO2를 발급하지 않음
+    Mock::given(method("POST"))
+        .and(path(SMARTID_LOGIN_PATH))
+        .respond_with(
+            ResponseTemplate::new(200).insert_header("Set-Cookie", "sToken=SMARTID-TOKEN; Path=/"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(PORTAL_SSO_PATH))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let config = USaintConfig {
+        smartid_login_url: format!("{}{}", server.uri(), SMARTID_LOGIN_PATH),
+        portal_sso_url: format!("{}{}", server.uri(), PORTAL_SSO_PATH),
+        ..common::mock_config(&server)
+    };
+    let guard = Arc::new(LoginGuard::new(3));
+    let login = || {
+        USaintSessionBuilder::new()
+            .config(config.clone())
+            .login_strategy(LoginStrategy::SmartId)
+            .login_guard(guard.clone())
+            .with_password("20211234", "wrong")
+    };
+
+    assert!(matches!(
+        login().await,
+        Err(USaintSessionError::MissingSmartIdToken)
+    ));
+    assert_eq!(guard.failures("20211234"), 1);
+
+    // 티켓 없이 끝난 로그인은 성공으로 기록되어 횟수를 초기화하지 않고 실패로 셈
+    assert!(matches!(
+        login().await,
+        Err(USaintSessionError::MissingMYSAPSSO2Cookie)
+    ));
+    assert_eq!(guard.failures("20211234"), 2);
+}
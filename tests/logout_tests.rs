@@ -0,0 +1,154 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::{
+    applications::{course_grades::CourseGradesApplication, Application},
+    config::USaintConfigBuilder,
+    session::{
+        credentials::Credentials, credentials::FnCredentialProvider, USaintSession,
+        USaintSessionError,
+    },
+    webdynpro::event::SapEventQueue,
+};
+use reqwest::Client;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn test_close_application_and_logout() {
+    let server = MockServer::start().await;
+    common::mount_login(&server).await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .and(body_string_contains("sap-sessioncmd=USR_ABORT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    common::mount_course_grades(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/sap/public/bc/icf/logoff"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    let session = Arc::new(
+        USaintSession::with_config(&provider, common::mock_config(&server))
+            .await
+            .unwrap(),
+    );
+
+    let course_grades_app = CourseGradesApplication::with_session(session.clone())
+        .await
+        .unwrap();
+    course_grades_app.close().await.unwrap();
+
+    session.logout().await.unwrap();
+    assert!(session.sso_token().is_none());
+}
+
+/// `Domain`과 `Path` 속성이 있는 SSO 쿠키를 발급하는 로그인 폼을 설정합니다.
+#[tokio::test]
+async fn test_close_sends_unload_event_queue() {
+    let server = MockServer::start().await;
+    common::mount_course_grades(&server).await;
+    let application = Application::with_config(
+        Arc::new(Client::new()),
+        common::mock_config(&server),
+        "ZCMB3W0017",
+    )
+    .await
+    .unwrap();
+
+    application.close().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let body = String::from_utf8(requests.last().unwrap().body.clone()).unwrap();
+    let form: Vec<(String, String)> = reqwest::Url::parse(&format!("http://localhost/?{}", body))
+        .unwrap()
+        .query_pairs()
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    let field = |name: &str| {
+        form.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    // 브라우저가 창을 닫을 때처럼 이벤트 큐를 마무리하면서 애플리케이션 종료를 요청
+    assert_eq!(field("sap-sessioncmd"), Some("USR_ABORT"));
+    assert_eq!(field("sap-wd-secure-id"), Some("SECURE-ID"));
+    let queue: SapEventQueue = field("SAPEVENTQUEUE").unwrap().parse().unwrap();
+    let names: Vec<_> = queue
+        .events()
+        .map(|event| format!("{}_{}", event.event, event.control))
+        .collect();
+    assert_eq!(names, ["ClientInspector_Notify", "Form_Request"]);
+}
+
+async fn mount_scoped_login(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(body_string_contains("sap-user"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Set-Cookie",
+                    "MYSAPSSO2=TICKET; Domain=127.0.0.1; Path=/sap/bc",
+                )
+                .set_body_string("<html><body>logged in</body></html>"),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_logout_clears_domain_scoped_cookie() {
+    let server = MockServer::start().await;
+    mount_scoped_login(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/sap/public/bc/icf/logoff"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    let session = USaintSession::with_config(&provider, common::mock_config(&server))
+        .await
+        .unwrap();
+    assert_eq!(session.sso_token().as_deref(), Some("TICKET"));
+
+    session.logout().await.unwrap();
+    assert!(session.sso_token().is_none());
+}
+
+#[tokio::test]
+async fn test_logout_clears_cookies_when_logoff_fails() {
+    let server = MockServer::start().await;
+    mount_scoped_login(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/sap/public/bc/icf/logoff"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    // WebDynpro 서버의 로그오프 요청은 연결에 실패
+    let config = USaintConfigBuilder::default()
+        .login_url(format!("{}{}", server.uri(), common::LOGIN_PATH))
+        .webdynpro_base_url("http://127.0.0.1:1")
+        .build()
+        .unwrap();
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    let session = USaintSession::with_config(&provider, config).await.unwrap();
+
+    assert!(matches!(
+        session.logout().await,
+        Err(USaintSessionError::RequestError(_))
+    ));
+    assert!(session.sso_token().is_none());
+}
@@ -0,0 +1,97 @@
+use mini_rusaint::webdynpro::{
+    element::{button::Button, combo_box::ComboBox, sap_table::SapTable, Element},
+    lsdata::{LsData, LsDataError, LsEvents, LsValue},
+};
+use scraper::Html;
+
+#[test]
+fn test_parse_lsdata() {
+    let lsdata =
+        LsData::parse(r#"{0:'2024\x2f2\x27학기',1:true,2:-12.5,3:{'a':[1,'b',null]},"4":"x y"}"#)
+            .unwrap();
+
+    assert_eq!(lsdata.get_str("0"), Some("2024/2'학기"));
+    assert_eq!(lsdata.get_bool("1"), Some(true));
+    assert_eq!(lsdata.get_number("2"), Some(-12.5));
+    assert_eq!(
+        lsdata.get("3"),
+        Some(&LsValue::Object(vec![(
+            "a".to_string(),
+            LsValue::Array(vec![
+                LsValue::Number(1.0),
+                LsValue::String("b".to_string()),
+                LsValue::Null
+            ])
+        )]))
+    );
+    assert_eq!(lsdata.get_str("4"), Some("x y"));
+    assert_eq!(lsdata.iter().count(), 5);
+}
+
+#[test]
+fn test_parse_invalid_lsdata() {
+    assert_eq!(LsData::parse("{0:'a'"), Err(LsDataError::UnexpectedEnd));
+    assert_eq!(LsData::parse("[1,2]"), Err(LsDataError::NotAnObject));
+    assert!(matches!(
+        LsData::parse("{0:'a';1:'b'}"),
+        Err(LsDataError::UnexpectedCharacter { found: ';', .. })
+    ));
+}
+
+#[test]
+fn test_parse_lsevents() {
+    let lsevents = LsEvents::parse(
+        r#"{'Press':[{'ResponseData':'delta','ClientAction':'submit'},{'Custom':1}],'Focus':[{},{}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(lsevents.names().collect::<Vec<_>>(), ["Press", "Focus"]);
+    let press = lsevents.get("Press").unwrap();
+    assert_eq!(
+        press.ucf_parameters,
+        [
+            ("ResponseData".to_string(), "delta".to_string()),
+            ("ClientAction".to_string(), "submit".to_string())
+        ]
+    );
+    assert_eq!(
+        press.custom_parameters,
+        [("Custom".to_string(), "1".to_string())]
+    );
+    assert!(!lsevents.contains("Select"));
+}
+
+#[test]
+fn test_elements_read_lsdata() {
+    let document = Html::parse_document(
+        r#"<html><body>
+        <div id="BTN" ct="B" lsdata="{0:'상세',2:false}" lsevents="{'Press':[{'ClientAction':'submit','ResponseData':'delta','EnqueueCardinality':'single'},{}]}">x</div>
+        <input id="CB" ct="CB" lsdata="{2:'CB-lb',3:'092',4:'2 학기'}" value="">
+        <div id="TABLE" ct="ST" lsdata="{2:42,3:12}"></div>
+        <div id="BROKEN" ct="B" lsdata="{0:"></div>
+        </body></html>"#,
+    );
+
+    let button = Button::find(&document, "BTN").unwrap();
+    assert_eq!(button.text(), "상세");
+    assert!(!button.is_enabled());
+    assert_eq!(
+        button
+            .press()
+            .ucf_parameters
+            .get("EnqueueCardinality")
+            .map(String::as_str),
+        Some("single")
+    );
+
+    let combo_box = ComboBox::find(&document, "CB").unwrap();
+    assert_eq!(combo_box.key(), Some("092"));
+    assert_eq!(combo_box.value(), "2 학기");
+    assert_eq!(combo_box.item_list_box_id(), Some("CB-lb"));
+
+    let table = SapTable::find(&document, "TABLE").unwrap();
+    assert_eq!(table.row_count(), Some(42));
+    assert_eq!(table.column_count(), Some(12));
+
+    assert!(Button::find(&document, "BROKEN").is_err());
+}
@@ -0,0 +1,71 @@
+use mini_rusaint::webdynpro::page::{
+    is_application_not_found, is_authorization_denied, is_login_page, is_session_expired, snippet,
+};
+
+#[test]
+fn test_login_page_is_session_expired() {
+    let body = r#"<form name="loginForm" method="post"><input type="text" name="sap-user" value=""><input type="hidden" name="sap-system-login" value="onLogin"></form>"#;
+
+    assert!(is_login_page(body));
+    assert!(is_session_expired(body));
+}
+
+#[test]
+fn test_session_expired_page() {
+    let body = "<html><body><p>The session has expired. Restart the application.</p></body></html>";
+
+    assert!(!is_login_page(body));
+    assert!(is_session_expired(body));
+    assert!(!is_session_expired(
+        r#"<form id="sap.client.SsrClient.form" action="/sap/bc/webdynpro/SAP/ZCMB3W0017"></form>"#
+    ));
+}
+
+#[test]
+fn test_free_text_is_not_session_expired() {
+    // 실행 중인 애플리케이션 화면의 내용이나 스크립트에 같은 문구가 있는 경우
+    let application_page = concat!(
+        r#"<html><body><form id="sap.client.SsrClient.form" action="/sap/bc/webdynpro/SAP/ZCMB3W0017"></form>"#,
+        r#"<span>세션이 만료되면 다시 로그인하세요.</span></body></html>"#
+    );
+    assert!(!is_session_expired(application_page));
+
+    let script_page = r#"<html><body><script>var form = 'name="sap-user"'; // sap-system-login, session has expired</script><p>ok</p></body></html>"#;
+    assert!(!is_login_page(script_page));
+    assert!(!is_session_expired(script_page));
+
+    // 델타 응답의 내용은 세션 만료 여부와 관계없음
+    let updates = r#"<?xml version="1.0" encoding="utf-8"?><updates><delta-update windowid="main"><control-update id="TEXT"><content><![CDATA[<span id="TEXT">The session has expired</span><input name="sap-user">]]></content></control-update></delta-update></updates>"#;
+    assert!(!is_session_expired(updates));
+}
+
+#[test]
+fn test_error_pages() {
+    assert!(is_authorization_denied(
+        "<html><body><p>You have no authorization for application ZCMB3W0017</p></body></html>"
+    ));
+    assert!(is_application_not_found(
+        "<html><body><p>Application ZCMB3W9999 does not exist</p></body></html>"
+    ));
+    assert!(is_application_not_found(
+        "<html><body><p>애플리케이션 ZCMB3W9999이 존재하지 않습니다.</p></body></html>"
+    ));
+
+    // 스크립트, lsdata, 일반 화면 문구에 들어 있는 비슷한 단어는 오류 페이지로 보지 않음
+    let page = concat!(
+        r#"<html><body><script>if (!item) { throw "not found"; } // does not exist, not authorized</script>"#,
+        r#"<span id="TEXT" ct="TV" lsdata="{0:'no authorization for application'}">검색 결과가 존재하지 않습니다.</span>"#,
+        r#"</body></html>"#
+    );
+    assert!(!is_authorization_denied(page));
+    assert!(!is_application_not_found(page));
+}
+
+#[test]
+fn test_page_snippet() {
+    let body = format!("<html>\n  <body>\n{}</body></html>", "x".repeat(1000));
+    let snippet = snippet(&body);
+
+    assert!(snippet.starts_with("<html> <body> xxx"));
+    assert_eq!(snippet.chars().count(), 300);
+}
@@ -0,0 +1,192 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::session::{
+    builder::USaintSessionBuilder,
+    lockout::LoginGuard,
+    password::{classify_policy_message, PasswordPolicyError},
+    LoginStrategy, USaintSessionError,
+};
+use wiremock::{
+    matchers::{body_string_contains, header_regex, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn password_change_page(message: &str) -> String {
+    format!(
+        concat!(
+            r#"<form method="post" action="/sap/bc/webdynpro/sap?sap-client=100">"#,
+            r#"<span class="urMsgBarTxt">{}</span>"#,
+            r#"<input type="hidden" name="sap-login-XSRF" value="XSRF-TOKEN">"#,
+            r#"<input type="hidden" name="sap-system-login-oninputprocessing" value="onLogin">"#,
+            r#"<input type="text" name="sap-user" value="">"#,
+            r#"<input type="password" name="sap-system-login-password-new"></form>"#
+        ),
+        message
+    )
+}
+
+/// 기존 비밀번호로 로그인하면 비밀번호 변경 폼과 로그온 세션 쿠키를 내려주도록 설정합니다.
+async fn mount_expired_login(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(body_string_contains("sap-system-login=onLogin"))
+        .and(body_string_contains("sap-password=old-password"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Set-Cookie", "sap-login-session=LOGON; Path=/")
+                .set_body_string(password_change_page(
+                    "Password has expired; change your password",
+                )),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_change_expired_password() {
+    let server = MockServer::start().await;
+    mount_expired_login(&server).await;
+    // 변경 폼의 숨은 필드와 로그온 세션 쿠키를 그대로 보내야 함
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(query_param("sap-client", "100"))
+        .and(header_regex("cookie", "sap-login-session=LOGON"))
+        .and(body_string_contains("sap-login-XSRF=XSRF-TOKEN"))
+        .and(body_string_contains(
+            "sap-system-login-oninputprocessing=onLogin",
+        ))
+        .and(body_string_contains("sap-system-login=onPasswordChange"))
+        .and(body_string_contains("sap-password=old-password"))
+        .and(body_string_contains(
+            "sap-system-login-password-repeat=new-password",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Set-Cookie", "MYSAPSSO2=TICKET; Path=/")
+                .set_body_string("<html><body>password changed</body></html>"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    // 재인증은 새 비밀번호로 진행되어야 함
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(body_string_contains("sap-system-login=onLogin"))
+        .and(body_string_contains("sap-password=new-password"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Set-Cookie", "MYSAPSSO2=TICKET2; Path=/")
+                .set_body_string("<html><body>logged in</body></html>"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let session = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_guard(Arc::new(LoginGuard::default()))
+        .with_password_change("20201234", "old-password", "new-password")
+        .await
+        .unwrap();
+
+    session.reauthenticate().await.unwrap();
+    assert_eq!(session.sso_token().as_deref(), Some("TICKET2"));
+}
+
+#[tokio::test]
+async fn test_change_expired_password_policy_error() {
+    let server = MockServer::start().await;
+    mount_expired_login(&server).await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(body_string_contains("sap-system-login=onPasswordChange"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(password_change_page(
+                "Choose a password that is different from your last 5 passwords",
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    let guard = Arc::new(LoginGuard::default());
+    let result = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_guard(guard.clone())
+        .with_password_change("20201234", "old-password", "new-password")
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(USaintSessionError::PasswordPolicy(
+            PasswordPolicyError::RecentlyUsed(_)
+        ))
+    ));
+    // 정책 위반은 계정 정보 오류가 아니므로 실패 횟수에 포함되지 않음
+    assert_eq!(guard.failures("20201234"), 0);
+}
+
+#[tokio::test]
+async fn test_change_password_not_expired() {
+    let server = MockServer::start().await;
+    common::mount_login(&server).await;
+
+    let result = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_guard(Arc::new(LoginGuard::default()))
+        .with_password_change("20201234", "old-password", "new-password")
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(USaintSessionError::PasswordNotExpired)
+    ));
+}
+
+#[tokio::test]
+async fn test_change_password_with_smart_id_strategy() {
+    let server = MockServer::start().await;
+
+    let result = USaintSessionBuilder::new()
+        .config(common::mock_config(&server))
+        .login_strategy(LoginStrategy::SmartId)
+        .login_guard(Arc::new(LoginGuard::default()))
+        .with_password_change("20201234", "old-password", "new-password")
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(USaintSessionError::PasswordChangeUnsupported(
+            LoginStrategy::SmartId
+        ))
+    ));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[test]
+fn test_classify_policy_message() {
+    assert!(matches!(
+        classify_policy_message("Password is too short (minimum length: 8)"),
+        Some(PasswordPolicyError::TooShort(_))
+    ));
+    assert!(matches!(
+        classify_policy_message("Password must contain at least 1 digit(s)"),
+        Some(PasswordPolicyError::MissingCharacterClass(_))
+    ));
+    assert!(matches!(
+        classify_policy_message("새 비밀번호가 일치하지 않습니다."),
+        Some(PasswordPolicyError::Mismatch(_))
+    ));
+    assert!(matches!(
+        classify_policy_message("You can only change your password once a day"),
+        Some(PasswordPolicyError::ChangeNotAllowed(_))
+    ));
+    assert!(classify_policy_message("Name or password is incorrect (repeat logon)").is_none());
+    // "last"가 들어 있다고 해서 재사용 금지로 분류하지 않음
+    assert!(!matches!(
+        classify_policy_message("Password is too short; last change was rejected"),
+        Some(PasswordPolicyError::RecentlyUsed(_))
+    ));
+}
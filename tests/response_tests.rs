@@ -0,0 +1,72 @@
+use mini_rusaint::webdynpro::response::{Update, WebDynproResponse};
+
+const DELTA_RESPONSE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<updates>
+  <full-update windowid="sapwd_main_window">
+    <content-update id="sapwd_main_window_root_"><![CDATA[<div id="ROOT">root</div>]]></content-update>
+  </full-update>
+  <delta-update windowid="sapwd_main_window">
+    <control-update id="ZCMB3W0017.ID_0001:VIW_MAIN.TABLE">
+      <content><![CDATA[<div id="ZCMB3W0017.ID_0001:VIW_MAIN.TABLE" ct="ST">table</div>]]></content>
+    </control-update>
+    <model-update model="ClientInspector" version="1"><![CDATA[{"a":1}]]></model-update>
+    <animation-update/>
+    <initialize-ids><![CDATA[ZCMB3W0017.ID_0001:VIW_MAIN.TABLE]]></initialize-ids>
+  </delta-update>
+  <script-call><![CDATA[UCF_JsUtil.focus();]]></script-call>
+  <redirect url="https://ecc.ssu.ac.kr/sap/public/bc/icf/logoff"/>
+</updates>"#;
+
+#[test]
+fn test_parse_delta_response() {
+    let response = WebDynproResponse::parse(DELTA_RESPONSE).unwrap();
+
+    assert!(!response.is_full_page());
+    assert_eq!(response.updates().len(), 6);
+    assert_eq!(
+        response.updates()[1],
+        Update::Control {
+            window_id: Some("sapwd_main_window".to_string()),
+            id: "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE".to_string(),
+            html: r#"<div id="ZCMB3W0017.ID_0001:VIW_MAIN.TABLE" ct="ST">table</div>"#.to_string(),
+        }
+    );
+    assert!(matches!(
+        &response.updates()[2],
+        Update::Model { model: Some(model), content, .. } if model == "ClientInspector" && content == r#"{"a":1}"#
+    ));
+
+    let fragment_ids: Vec<_> = response.fragments().map(|(id, _)| id).collect();
+    assert_eq!(
+        fragment_ids,
+        [
+            "sapwd_main_window_root_",
+            "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE"
+        ]
+    );
+    assert_eq!(
+        response.script_calls().collect::<Vec<_>>(),
+        ["UCF_JsUtil.focus();"]
+    );
+    assert_eq!(
+        response.redirect_url(),
+        Some("https://ecc.ssu.ac.kr/sap/public/bc/icf/logoff")
+    );
+    assert!(response.html().contains(r#"ct="ST">table"#));
+}
+
+#[test]
+fn test_parse_full_page_response() {
+    let body = "<!DOCTYPE html><html><body>page</body></html>";
+    let response: WebDynproResponse = body.parse().unwrap();
+
+    assert!(response.is_full_page());
+    assert!(response.updates().is_empty());
+    assert_eq!(response.html(), body);
+}
+
+#[test]
+fn test_parse_invalid_delta_response() {
+    assert!(WebDynproResponse::parse("<updates><control-update></updates>").is_err());
+    assert!(WebDynproResponse::parse("<updates><control-update/></updates>").is_err());
+}
@@ -0,0 +1,141 @@
+use mini_rusaint::{
+    applications::course_grades::model::{CourseGrade, SemesterGrade},
+    webdynpro::element::{
+        sap_table::{SapTable, SapTableCellKind},
+        Element, ElementError,
+    },
+};
+use scraper::Html;
+
+const SEMESTER_TABLE: &str = r#"<html><body>
+<table id="TABLE" ct="ST" lsdata="{2:2,3:14}"><tbody id="TABLE-contentTBody">
+<tr rr="0"><th subct="STHC"></th><th subct="STHC">학기</th><th subct="STHC">학년도</th>
+<th subct="STHC">신청학점</th><th subct="STHC">취득학점</th><th subct="STHC">P/F학점</th>
+<th subct="STHC">평점평균</th><th subct="STHC">평점계</th><th subct="STHC">산술평균</th>
+<th subct="STHC">학기별석차</th><th subct="STHC">전체석차</th><th subct="STHC">학사경고</th>
+<th subct="STHC">상담여부</th><th subct="STHC">유급</th></tr>
+<tr rr="1"><td><span id="SEL1" ct="R"></span></td><td>1 학기</td><td>2023</td>
+<td>19.5</td><td>19.5</td><td>0</td><td>4.12</td><td>80.3</td><td>93.2</td>
+<td>3/42</td><td> 10/180 </td><td>N</td><td>Y</td><td>N</td></tr>
+<tr rr="2"><td><span id="SEL2" ct="R"></span></td><td>2 학기</td><td>2023</td>
+<td>18</td><td>18</td><td>1</td><td>3.9</td><td>70.2</td><td>91</td>
+<td>5/40</td><td>20/175</td><td>N</td><td>N</td><td>N</td></tr>
+</tbody></table>
+</body></html>"#;
+
+const COURSE_TABLE: &str = r#"<html><body>
+<table id="COURSES" ct="ST"><tbody id="COURSES-contentTBody">
+<tr><td subct="STHC"></td><td subct="STHC">성적</td><td subct="STHC">등급</td>
+<td subct="STHC">과목명</td><td subct="STHC">상세성적</td><td subct="STHC">과목학점</td>
+<td subct="STHC">교수명</td><td subct="STHC">비고</td><td subct="STHC">과목코드</td></tr>
+<tr rr="1"><td></td><td>95</td><td>A+</td><td><span id="NAME1" ct="TV">자료구조</span></td>
+<td><div id="DETAIL1" ct="B">조회</div></td><td>3.0</td><td>홍길동</td><td></td><td>21501015</td></tr>
+<tr rr="2"><td></td><td>P</td><td>P</td><td>채플</td><td></td><td>0.5</td><td>김철수</td>
+<td><span id="CHECK2" ct="C"></span></td><td>21500002</td></tr>
+<tr rr="0"><td></td><td></td><td></td><td></td><td></td><td></td><td></td><td></td><td></td></tr>
+</tbody></table>
+</body></html>"#;
+
+#[test]
+fn test_read_rows_by_header() {
+    let document = Html::parse_document(SEMESTER_TABLE);
+    let table = SapTable::find(&document, "TABLE").unwrap();
+
+    assert_eq!(table.row_count(), Some(2));
+    assert_eq!(table.headers()[1], "학기");
+    assert_eq!(table.rows().len(), 2);
+
+    let row = &table.rows()[0];
+    assert_eq!(row.index(), Some(1));
+    assert_eq!(row.text("학기").unwrap(), "1 학기");
+    assert_eq!(row.parse::<u32>("학년도").unwrap(), 2023);
+    assert_eq!(row.get("").unwrap().kind(), &SapTableCellKind::RadioButton);
+    assert_eq!(row.get("").unwrap().control_id(), Some("SEL1"));
+
+    // 서버에서 열 순서가 바뀌어도 열 이름으로 읽음
+    let grades: Vec<SemesterGrade> = table
+        .rows()
+        .iter()
+        .map(SemesterGrade::from_row)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(grades[0].year, 2023);
+    assert_eq!(grades[0].semester, "1 학기");
+    assert_eq!(grades[0].general_rank, (10, 180));
+    assert!(grades[0].consult);
+    assert_eq!(grades[1].semester_rank, (5, 40));
+}
+
+#[test]
+fn test_cell_kinds_and_controls() {
+    let document = Html::parse_document(COURSE_TABLE);
+    let table = SapTable::find(&document, "COURSES").unwrap();
+
+    // 헤더 행과 rr="0" 자리 표시 행은 제외
+    assert_eq!(table.rows().len(), 2);
+
+    let first = &table.rows()[0];
+    assert_eq!(first.get("과목명").unwrap().kind(), &SapTableCellKind::Text);
+    assert_eq!(first.get("과목명").unwrap().control_ids(), ["NAME1"]);
+    assert_eq!(CourseGrade::detail_button_id(first), Some("DETAIL1"));
+
+    let second = &table.rows()[1];
+    assert_eq!(
+        second.get("비고").unwrap().kind(),
+        &SapTableCellKind::CheckBox
+    );
+    assert_eq!(CourseGrade::detail_button_id(second), None);
+
+    let course = CourseGrade::from_row(second).unwrap();
+    assert_eq!(course.course_name, "채플");
+    assert_eq!(course.course_credits, 0.5);
+    assert_eq!(course.course_code, "21500002");
+}
+
+const DETAIL_TABLE: &str = r#"<html><body>
+<table id="DETAIL" ct="ST"><tbody id="DETAIL-contentTBody">
+<tr><th subct="STHC"></th><th subct="STHC">학년도</th><th subct="STHC">학기</th>
+<th subct="STHC">과목코드</th><th subct="STHC">과목명</th><th subct="STHC">출석</th>
+<th subct="STHC">중간고사</th><th subct="STHC">기말고사</th><th subct="STHC">과제</th></tr>
+<tr rr="1"><td></td><td>2024</td><td>2 학기</td><td>21501015</td><td>자료구조</td>
+<td>10</td><td>28.5</td><td>{}</td><td></td></tr>
+</tbody></table>
+</body></html>"#;
+
+#[test]
+fn test_create_detailed_grades() {
+    let document = Html::parse_document(&DETAIL_TABLE.replace("{}", "35"));
+    let table = SapTable::find(&document, "DETAIL").unwrap();
+
+    // 과목 정보 열은 위치와 관계없이 이름으로 제외하고, 빈 점수는 건너뜀
+    let grades = CourseGrade::create_detailed_grades(&table).unwrap();
+    assert_eq!(grades.len(), 3);
+    assert_eq!(grades["출석"], 10.0);
+    assert_eq!(grades["중간고사"], 28.5);
+    assert_eq!(grades["기말고사"], 35.0);
+
+    // 숫자가 아닌 점수는 버리지 않고 오류로 처리
+    let document = Html::parse_document(&DETAIL_TABLE.replace("{}", "미입력"));
+    let table = SapTable::find(&document, "DETAIL").unwrap();
+    assert!(matches!(
+        CourseGrade::create_detailed_grades(&table),
+        Err(ElementError::InvalidCell { column, value, .. }) if column == "기말고사" && value == "미입력"
+    ));
+}
+
+#[test]
+fn test_missing_and_invalid_columns() {
+    let document = Html::parse_document(COURSE_TABLE);
+    let table = SapTable::find(&document, "COURSES").unwrap();
+    let row = &table.rows()[0];
+
+    assert!(matches!(
+        row.text("학년도"),
+        Err(ElementError::MissingColumn { id, column }) if id == "COURSES" && column == "학년도"
+    ));
+    assert!(matches!(
+        row.parse::<f32>("등급"),
+        Err(ElementError::InvalidCell { value, .. }) if value == "A+"
+    ));
+    assert!(SemesterGrade::from_row(row).is_err());
+}
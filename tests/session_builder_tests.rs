@@ -0,0 +1,167 @@
+mod common;
+
+use std::{sync::Arc, time::Duration};
+
+use mini_rusaint::session::{
+    lockout::LoginGuard, store::SessionSnapshot, LoginStrategy, USaintSession, USaintSessionError,
+};
+use reqwest::header::{HeaderName, HeaderValue};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn test_builder_sends_custom_headers() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(header("user-agent", "mini-rusaint-test"))
+        .and(header("x-campus-proxy", "1"))
+        .respond_with(
+            ResponseTemplate::new(200).insert_header("Set-Cookie", "MYSAPSSO2=TICKET; Path=/"),
+        )
+        .mount(&server)
+        .await;
+
+    let session = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .user_agent("mini-rusaint-test")
+        .default_header(
+            HeaderName::from_static("x-campus-proxy"),
+            HeaderValue::from_static("1"),
+        )
+        .with_password("20201234", "password")
+        .await;
+
+    assert!(session.is_ok());
+}
+
+#[tokio::test]
+async fn test_builder_read_timeout() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+        .mount(&server)
+        .await;
+
+    let result = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .read_timeout(Duration::from_millis(200))
+        .with_password("20201234", "password")
+        .await;
+
+    assert!(matches!(result, Err(USaintSessionError::RequestError(e)) if e.is_timeout()));
+}
+
+#[tokio::test]
+async fn test_with_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(common::LOGIN_PATH))
+        .and(header("cookie", "MYSAPSSO2=VALID"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>welcome</html>"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string(r#"<form><input type="text" name="sap-user" value=""></form>"#),
+        )
+        .mount(&server)
+        .await;
+
+    let session = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .with_token("VALID")
+        .await
+        .unwrap();
+    assert!(session.snapshot().contains("MYSAPSSO2"));
+
+    let result = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .with_token("EXPIRED")
+        .await;
+    assert!(matches!(result, Err(USaintSessionError::InvalidToken)));
+}
+
+#[tokio::test]
+async fn test_restore_uses_builder_settings() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>welcome</html>"))
+        .mount(&server)
+        .await;
+
+    let guard = Arc::new(LoginGuard::default());
+    let session = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .login_strategy(LoginStrategy::SmartId)
+        .login_guard(guard.clone())
+        .with_token("VALID")
+        .await
+        .unwrap();
+
+    assert_eq!(session.login_strategy(), LoginStrategy::SmartId);
+    assert!(Arc::ptr_eq(session.login_guard(), &guard));
+    // 토큰으로 만든 세션에는 다시 인증할 계정 정보가 없음
+    assert!(!session.can_reauthenticate());
+    assert!(matches!(
+        session.reauthenticate().await,
+        Err(USaintSessionError::MissingCredentials)
+    ));
+}
+
+#[tokio::test]
+async fn test_save_and_restore_scoped_cookie() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).insert_header(
+            "Set-Cookie",
+            "MYSAPSSO2=TICKET; Domain=127.0.0.1; Path=/sap/bc",
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(common::LOGIN_PATH))
+        .and(header("cookie", "MYSAPSSO2=TICKET"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>welcome</html>"))
+        .mount(&server)
+        .await;
+
+    let session = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .with_password("20201234", "password")
+        .await
+        .unwrap();
+
+    let path =
+        std::env::temp_dir().join(format!("mini-rusaint-session-{}.json", std::process::id()));
+    session.save_to_file(&path).await.unwrap();
+
+    // 로그온 티켓이 들어 있으므로 소유자만 읽을 수 있어야 함
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let snapshot: SessionSnapshot = serde_json::from_str(&contents).unwrap();
+    assert_eq!(snapshot.cookies.len(), 1);
+    assert_eq!(snapshot.cookies[0].domain.as_deref(), Some("127.0.0.1"));
+    assert_eq!(snapshot.cookies[0].path.as_deref(), Some("/sap/bc"));
+
+    let restored = USaintSession::builder()
+        .config(common::mock_config(&server))
+        .from_snapshot(&snapshot)
+        .await
+        .unwrap();
+    assert_eq!(restored.snapshot(), snapshot);
+}
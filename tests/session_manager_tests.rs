@@ -0,0 +1,79 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::session::{
+    credentials::{CredentialProvider, Credentials, FnCredentialProvider},
+    manager::{SessionManager, SessionManagerError},
+    USaintSession,
+};
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn mount_account_login(server: &MockServer, student_id: &str, expected_logins: u64) {
+    Mock::given(method("POST"))
+        .and(path(common::LOGIN_PATH))
+        .and(body_string_contains(format!("sap-user={}", student_id)))
+        .respond_with(ResponseTemplate::new(200).insert_header(
+            "Set-Cookie",
+            format!("MYSAPSSO2=TICKET-{}; Path=/", student_id),
+        ))
+        .expect(expected_logins)
+        .mount(server)
+        .await;
+}
+
+fn provider(student_id: &'static str) -> impl CredentialProvider + Send + Sync + 'static {
+    FnCredentialProvider::new(move || Ok(Credentials::new(student_id, "password")))
+}
+
+#[tokio::test]
+async fn test_session_manager_caches_sessions_per_account() {
+    let server = MockServer::start().await;
+    mount_account_login(&server, "20201234", 2).await;
+    mount_account_login(&server, "20205678", 1).await;
+
+    let config = common::mock_config(&server);
+    let manager = SessionManager::new(1)
+        .with_builder(move || USaintSession::builder().config(config.clone()));
+    manager.add_account("20201234", provider("20201234")).await;
+    manager.add_account("20205678", provider("20205678")).await;
+
+    let first = manager.session("20201234").await.unwrap();
+    let cached = manager.session("20201234").await.unwrap();
+    let other = manager.session("20205678").await.unwrap();
+
+    assert!(Arc::ptr_eq(&first, &cached));
+    assert!(!Arc::ptr_eq(&first.client, &other.client));
+    assert_eq!(first.sso_token().as_deref(), Some("TICKET-20201234"));
+    assert_eq!(other.sso_token().as_deref(), Some("TICKET-20205678"));
+
+    assert!(manager.evict("20201234").await.is_some());
+    let relogged = manager.session("20201234").await.unwrap();
+    assert!(!Arc::ptr_eq(&first, &relogged));
+
+    assert!(matches!(
+        manager.session("unknown").await,
+        Err(SessionManagerError::UnknownAccount(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_session_manager_rejects_mismatched_credentials() {
+    let server = MockServer::start().await;
+    mount_account_login(&server, "20205678", 0).await;
+
+    let config = common::mock_config(&server);
+    let manager = SessionManager::new(1)
+        .with_builder(move || USaintSession::builder().config(config.clone()));
+    // 잘못 설정된 제공자가 다른 학생의 계정 정보를 반환
+    manager.add_account("20201234", provider("20205678")).await;
+
+    assert!(matches!(
+        manager.session("20201234").await,
+        Err(SessionManagerError::MismatchedCredentials { student_id, provided })
+            if student_id == "20201234" && provided == "20205678"
+    ));
+}
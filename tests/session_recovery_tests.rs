@@ -0,0 +1,177 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::{
+    applications::{course_grades::CourseGradesApplication, Application, ApplicationError},
+    session::{
+        credentials::{Credentials, FnCredentialProvider},
+        USaintSession,
+    },
+    webdynpro::element::button::Button,
+};
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const APP_NAME: &str = "ZCMB3W0017";
+const LOGIN_PAGE: &str = r#"<form><input type="text" name="sap-user" value=""></form>"#;
+const TABLE_ID: &str = "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE";
+
+async fn session(server: &MockServer) -> Arc<USaintSession> {
+    common::mount_login(server).await;
+    let provider = FnCredentialProvider::new(|| Ok(Credentials::new("20201234", "password")));
+    Arc::new(
+        USaintSession::with_config(&provider, common::mock_config(server))
+            .await
+            .unwrap(),
+    )
+}
+
+/// 처음 한 번은 로그인 폼으로 응답해 세션 만료를 흉내 냅니다.
+async fn mount_expired_once(server: &MockServer, body_contains: &str) {
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .and(body_string_contains(body_contains))
+        .respond_with(ResponseTemplate::new(200).set_body_string(LOGIN_PAGE))
+        .up_to_n_times(1)
+        .mount(server)
+        .await;
+}
+
+async fn count_requests(server: &MockServer, http_method: &str, body_contains: &str) -> usize {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.method.as_str() == http_method)
+        .filter(|request| request.url.path() == common::COURSE_GRADES_PATH)
+        .filter(|request| String::from_utf8_lossy(&request.body).contains(body_contains))
+        .count()
+}
+
+#[tokio::test]
+async fn test_recovered_session_does_not_replay_events() {
+    let server = MockServer::start().await;
+    let session = session(&server).await;
+    mount_expired_once(&server, "Button_Press").await;
+    let page = common::ssr_page(APP_NAME, r#"<div id="BTN" ct="B"><span>조회</span></div>"#);
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page.clone()))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page))
+        .mount(&server)
+        .await;
+
+    let application = Application::with_session(session, APP_NAME).await.unwrap();
+    let button = application.find_element::<Button>("BTN").await.unwrap();
+
+    // 첫 화면으로 초기화된 애플리케이션에 이전 화면의 이벤트를 다시 보내지 않음
+    assert!(matches!(
+        application.send_event(button.press()).await,
+        Err(ApplicationError::SessionRecovered)
+    ));
+    assert_eq!(count_requests(&server, "POST", "Button_Press").await, 1);
+    assert_eq!(count_requests(&server, "GET", "").await, 2);
+
+    // 다시 연 애플리케이션에서는 처음부터 다시 수행할 수 있음
+    let button = application.find_element::<Button>("BTN").await.unwrap();
+    application.send_event(button.press()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_course_grades_replays_operation_after_recovery() {
+    let server = MockServer::start().await;
+    let session = session(&server).await;
+    mount_expired_once(&server, "VerticalScroll").await;
+
+    // 화면에는 전체 2개 학기 중 1개만 보여 스크롤이 필요한 테이블
+    let table = common::semester_grades_table().replace("{2:1,", "{2:2,");
+    let page = common::ssr_page(APP_NAME, &table);
+    let scrolled_table = table.replace(
+        "</tbody>",
+        r#"<tr rr="2"><td></td><td>2024</td><td>1 학기</td><td>18</td><td>18</td><td>0</td><td>4.0</td><td>72.0</td><td>90.0</td><td>5/50</td><td>12/200</td><td>N</td><td>N</td><td>N</td></tr></tbody>"#,
+    );
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page.clone()))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .and(body_string_contains("VerticalScroll"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            r#"<updates><delta-update windowid="main"><control-update id="{}"><content><![CDATA[{}]]></content></control-update></delta-update></updates>"#,
+            TABLE_ID, scrolled_table
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page))
+        .mount(&server)
+        .await;
+
+    let application = CourseGradesApplication::with_session(session)
+        .await
+        .unwrap();
+    let grades = application.get_all_semester_grades().await.unwrap();
+
+    assert_eq!(grades.len(), 2);
+    assert_eq!(count_requests(&server, "POST", "VerticalScroll").await, 2);
+    assert_eq!(count_requests(&server, "GET", "").await, 2);
+}
+
+#[tokio::test]
+async fn test_restored_session_is_not_renewable() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(common::LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>welcome</html>"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(common::ssr_page(
+            APP_NAME,
+            r#"<div id="BTN" ct="B"><span>조회</span></div>"#,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(LOGIN_PAGE))
+        .mount(&server)
+        .await;
+
+    let session = Arc::new(
+        USaintSession::builder()
+            .config(common::mock_config(&server))
+            .with_token("VALID")
+            .await
+            .unwrap(),
+    );
+    let application = Application::with_session(session, APP_NAME).await.unwrap();
+    let button = application.find_element::<Button>("BTN").await.unwrap();
+
+    // 계정 정보가 없으므로 로그인을 시도하지 않고 바로 알림
+    assert!(matches!(
+        application.send_event(button.press()).await,
+        Err(ApplicationError::SessionNotRenewable)
+    ));
+    let login_posts = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.method.as_str() == "POST")
+        .filter(|request| request.url.path() == common::LOGIN_PATH)
+        .count();
+    assert_eq!(login_posts, 0);
+}
@@ -0,0 +1,100 @@
+use mini_rusaint::session::{
+    login::parse_login_failure,
+    store::{SessionSnapshot, StoredCookie},
+    USaintSession, USaintSessionError,
+};
+use reqwest::StatusCode;
+
+#[test]
+fn test_session_snapshot_from_cookie_header() {
+    let mut snapshot = SessionSnapshot::default();
+    snapshot.add_cookie_header(
+        "https://ecc.ssu.ac.kr",
+        "MYSAPSSO2=AjQxMDMBABgA; SAP_SESSIONID_SSP_100=abc%3d",
+    );
+
+    assert!(snapshot.contains("MYSAPSSO2"));
+    assert_eq!(
+        snapshot.cookies[1],
+        StoredCookie::new("https://ecc.ssu.ac.kr", "SAP_SESSIONID_SSP_100", "abc%3d")
+    );
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let restored: SessionSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(snapshot, restored);
+}
+
+#[test]
+fn test_stored_cookie_attributes() {
+    let cookie = StoredCookie {
+        domain: Some("ssu.ac.kr".to_string()),
+        path: Some("/sap".to_string()),
+        secure: true,
+        ..StoredCookie::new("https://ecc.ssu.ac.kr", "MYSAPSSO2", "TICKET")
+    };
+    assert_eq!(
+        cookie.to_set_cookie(),
+        "MYSAPSSO2=TICKET; Path=/sap; Domain=ssu.ac.kr; Secure"
+    );
+
+    // 속성이 없던 이전 형식의 스냅샷도 읽을 수 있음
+    let snapshot: SessionSnapshot = serde_json::from_str(
+        r#"{"cookies":[{"url":"https://ecc.ssu.ac.kr","name":"MYSAPSSO2","value":"TICKET"}]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        snapshot.cookies[0].to_set_cookie(),
+        "MYSAPSSO2=TICKET; Path=/"
+    );
+}
+
+#[tokio::test]
+async fn test_restore_session_without_sso_cookie() {
+    let mut snapshot = SessionSnapshot::default();
+    snapshot.add_cookie_header("https://ecc.ssu.ac.kr", "sap-usercontext=sap-client%3d100");
+
+    let result = USaintSession::from_snapshot(&snapshot).await;
+
+    assert!(matches!(
+        result,
+        Err(USaintSessionError::InvalidStoredSession)
+    ));
+}
+
+#[test]
+fn test_parse_login_failure() {
+    let login_page = |message: &str| {
+        format!(
+            r#"<form><span class="urMsgBarTxt">{}</span><input type="text" name="sap-user" value=""></form>"#,
+            message
+        )
+    };
+
+    assert!(matches!(
+        parse_login_failure(StatusCode::OK, &login_page("Name or password is incorrect (repeat logon)")),
+        Some(USaintSessionError::WrongPassword(message)) if message == "Name or password is incorrect (repeat logon)"
+    ));
+    assert!(matches!(
+        parse_login_failure(
+            StatusCode::OK,
+            &login_page("User is locked. Please notify the person responsible")
+        ),
+        Some(USaintSessionError::AccountLocked(_))
+    ));
+    assert!(matches!(
+        parse_login_failure(
+            StatusCode::OK,
+            &login_page("Password has expired; change your password")
+        ),
+        Some(USaintSessionError::PasswordExpired(_))
+    ));
+    assert!(matches!(
+        parse_login_failure(StatusCode::OK, &login_page("User 20201234 does not exist")),
+        Some(USaintSessionError::UnknownUser(_))
+    ));
+    assert!(matches!(
+        parse_login_failure(StatusCode::SERVICE_UNAVAILABLE, "<html></html>"),
+        Some(USaintSessionError::ServerMaintenance(_))
+    ));
+    assert!(parse_login_failure(StatusCode::OK, "<html><body>ok</body></html>").is_none());
+}
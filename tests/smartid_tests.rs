@@ -0,0 +1,97 @@
+mod common;
+
+use mini_rusaint::{
+    config::USaintConfig,
+    session::{builder::USaintSessionBuilder, LoginStrategy, USaintSessionError},
+};
+use wiremock::{
+    matchers::{body_string_contains, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const SMARTID_LOGIN_PATH: &str = "/Symtra_sso/smln_pcs.asp";
+const PORTAL_SSO_PATH: &str = "/webSSO/sso.jsp";
+
+fn smartid_config(server: &MockServer) -> USaintConfig {
+    USaintConfig {
+        smartid_login_url: format!("{}{}", server.uri(), SMARTID_LOGIN_PATH),
+        portal_sso_url: format!("{}{}", server.uri(), PORTAL_SSO_PATH),
+        ..common::mock_config(server)
+    }
+}
+
+#[tokio::test]
+async fn test_smartid_login() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(SMARTID_LOGIN_PATH))
+        .and(body_string_contains("userid=20211234"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Set-Cookie", "sToken=SMARTID-TOKEN; Path=/")
+                .set_body_string("<script>location.href='/irj/portal';</script>"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(PORTAL_SSO_PATH))
+        .and(query_param("sToken", "SMARTID-TOKEN"))
+        .and(query_param("sIdno", "20211234"))
+        .respond_with(
+            ResponseTemplate::new(200).insert_header("Set-Cookie", "MYSAPSSO2=TICKET; Path=/"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let session = USaintSessionBuilder::new()
+        .config(smartid_config(&server))
+        .login_strategy(LoginStrategy::SmartId)
+        .with_password("20211234", "password")
+        .await
+        .unwrap();
+
+    assert_eq!(session.sso_token().as_deref(), Some("TICKET"));
+}
+
+#[tokio::test]
+async fn test_smartid_login_wrong_password() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(SMARTID_LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<script>alert("비밀번호가 일치하지 않습니다."); history.go(-1);</script>"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let result = USaintSessionBuilder::new()
+        .config(smartid_config(&server))
+        .login_strategy(LoginStrategy::SmartId)
+        .with_password("20211234", "wrong")
+        .await;
+
+    assert!(matches!(result, Err(USaintSessionError::WrongPassword(_))));
+}
+
+#[tokio::test]
+async fn test_smartid_login_without_token() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(SMARTID_LOGIN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .mount(&server)
+        .await;
+
+    let result = USaintSessionBuilder::new()
+        .config(smartid_config(&server))
+        .login_strategy(LoginStrategy::SmartId)
+        .with_password("20211234", "password")
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(USaintSessionError::MissingSmartIdToken)
+    ));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::webdynpro::client::{SapSsrClient, SapSsrClientError};
+use reqwest::{Client, StatusCode};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const APP_NAME: &str = "ZCMB3W0017";
+
+async fn fetch(response: ResponseTemplate) -> Result<SapSsrClient, SapSsrClientError> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(response)
+        .mount(&server)
+        .await;
+
+    SapSsrClient::new(
+        Arc::new(Client::new()),
+        &common::mock_config(&server),
+        APP_NAME,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_ssr_client_from_form() {
+    let client = fetch(ResponseTemplate::new(200).set_body_string(common::ssr_page(APP_NAME, "")))
+        .await
+        .unwrap();
+
+    assert_eq!(client.wd_secure_id, "SECURE-ID");
+    assert_eq!(client.charset, "utf-8");
+    assert!(client.action_url.starts_with(common::COURSE_GRADES_PATH));
+}
+
+#[tokio::test]
+async fn test_ssr_client_classifies_pages_without_form() {
+    let login_page = r#"<form><input type="text" name="sap-user" value=""></form>"#;
+    assert!(matches!(
+        fetch(ResponseTemplate::new(401).set_body_string(login_page)).await,
+        Err(SapSsrClientError::SessionExpired { .. })
+    ));
+
+    let error = fetch(ResponseTemplate::new(200).set_body_string(
+        "<html><body>You have no authorization for application ZCMB3W0017</body></html>",
+    ))
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        SapSsrClientError::AuthorizationDenied { .. }
+    ));
+    assert!(error.snippet().unwrap().contains("no authorization"));
+
+    assert!(matches!(
+        fetch(ResponseTemplate::new(404)).await,
+        Err(SapSsrClientError::ApplicationNotFound { .. })
+    ));
+    assert!(matches!(
+        fetch(ResponseTemplate::new(500).set_body_string("Internal Server Error")).await,
+        Err(SapSsrClientError::HttpStatus {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            ..
+        })
+    ));
+    assert!(matches!(
+        fetch(ResponseTemplate::new(200).set_body_string("<html></html>")).await,
+        Err(SapSsrClientError::MissingSsrForm { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_ssr_client_missing_secure_id() {
+    let body = common::ssr_page(APP_NAME, "").replace("SECURE-ID", "");
+
+    assert!(matches!(
+        fetch(ResponseTemplate::new(200).set_body_string(body)).await,
+        Err(SapSsrClientError::MissingFormField {
+            field: "sap-wd-secure-id",
+            ..
+        })
+    ));
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use std::sync::Arc;
+
+use mini_rusaint::applications::{Application, ApplicationError};
+use reqwest::Client;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const APP_NAME: &str = "ZCMB3W0017";
+
+/// 전체 `row_count`개 행 중 주어진 행 번호의 행만 그려진 테이블
+fn table(row_count: u32, rows: &[u32]) -> String {
+    let rows: String = rows
+        .iter()
+        .map(|index| format!(r#"<tr rr="{0}"><td>{0}</td><td>과목{0}</td></tr>"#, index))
+        .collect();
+    format!(
+        r#"<table id="TABLE" ct="ST" lsdata="{{2:{}}}"><tbody id="TABLE-contentTBody"><tr rr="0"><th>번호</th><th>과목명</th></tr>{}</tbody></table>"#,
+        row_count, rows
+    )
+}
+
+fn table_update(row_count: u32, rows: &[u32]) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><updates><delta-update windowid="main"><control-update id="TABLE"><content><![CDATA[{}]]></content></control-update></delta-update></updates>"#,
+        table(row_count, rows)
+    )
+}
+
+async fn mount_scroll(server: &MockServer, first_visible_item_index: u32, body: String) {
+    Mock::given(method("POST"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .and(body_string_contains("VerticalScroll"))
+        .and(body_string_contains(format!(
+            "FirstVisibleItemIndex%7EE004{}",
+            first_visible_item_index
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn application(server: &MockServer, page: &str) -> Application {
+    Mock::given(method("GET"))
+        .and(path(common::COURSE_GRADES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_string(common::ssr_page(APP_NAME, page)))
+        .mount(server)
+        .await;
+
+    Application::with_config(
+        Arc::new(Client::new()),
+        common::mock_config(server),
+        APP_NAME,
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_read_table_rows_scrolls_until_complete() {
+    let server = MockServer::start().await;
+    // 스크롤 응답은 이미 읽은 행과 겹칠 수 있음
+    mount_scroll(&server, 3, table_update(5, &[2, 3, 4])).await;
+    mount_scroll(&server, 5, table_update(5, &[4, 5])).await;
+    let application = application(&server, &table(5, &[1, 2])).await;
+
+    let rows = application.read_table_rows("TABLE").await.unwrap();
+
+    let indices: Vec<_> = rows.iter().filter_map(|row| row.index()).collect();
+    assert_eq!(indices, [1, 2, 3, 4, 5]);
+    assert_eq!(rows[4].text("과목명").unwrap(), "과목5");
+}
+
+#[tokio::test]
+async fn test_read_table_rows_without_scrolling() {
+    let server = MockServer::start().await;
+    let application = application(&server, &table(2, &[1, 2])).await;
+
+    let rows = application.read_table_rows("TABLE").await.unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_read_table_rows_reports_truncated_table() {
+    let server = MockServer::start().await;
+    // 스크롤해도 같은 행만 돌아오면 전체 행을 읽을 수 없음
+    mount_scroll(&server, 3, table_update(4, &[1, 2])).await;
+    let application = application(&server, &table(4, &[1, 2])).await;
+
+    let result = application.read_table_rows("TABLE").await;
+
+    assert!(matches!(
+        result,
+        Err(ApplicationError::IncompleteTable {
+            expected: 4,
+            read: 2,
+            ..
+        })
+    ));
+}
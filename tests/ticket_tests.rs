@@ -0,0 +1,104 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{Duration, TimeZone, Utc};
+use mini_rusaint::session::ticket::{LogonTicket, LogonTicketError};
+
+fn utf16le(value: &str) -> Vec<u8> {
+    value
+        .encode_utf16()
+        .flat_map(|unit| unit.to_le_bytes())
+        .collect()
+}
+
+fn field(id: u8, value: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![id];
+    bytes.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    bytes.extend_from_slice(value);
+    bytes
+}
+
+fn ticket_bytes() -> Vec<u8> {
+    ticket_bytes_with_validity(&8u32.to_be_bytes(), &30u32.to_be_bytes())
+}
+
+fn ticket_bytes_with_validity(hours: &[u8], minutes: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x02];
+    bytes.extend_from_slice(b"4103");
+    bytes.extend(field(0x01, &utf16le("20201234    ")));
+    bytes.extend(field(0x02, &utf16le("100")));
+    bytes.extend(field(0x03, &utf16le("SSP")));
+    bytes.extend(field(0x04, &utf16le("202410181230")));
+    bytes.extend(field(0x05, hours));
+    bytes.extend(field(0x07, minutes));
+    bytes.extend(field(0xFF, &[0x30, 0x82]));
+    bytes
+}
+
+#[test]
+fn test_parse_logon_ticket() {
+    let encoded = BASE64
+        .encode(ticket_bytes())
+        .replace('+', "%2b")
+        .replace('/', "%2f")
+        .replace('=', "%3d");
+    let ticket: LogonTicket = encoded.parse().unwrap();
+
+    assert_eq!(ticket.version(), 2);
+    assert_eq!(ticket.user(), "20201234");
+    assert_eq!(ticket.client(), Some("100"));
+    assert_eq!(ticket.system_id(), Some("SSP"));
+    assert!(ticket.is_signed());
+    assert_eq!(
+        ticket.created_at(),
+        Utc.with_ymd_and_hms(2024, 10, 18, 12, 30, 0).unwrap()
+    );
+    assert_eq!(ticket.validity(), Duration::minutes(8 * 60 + 30));
+    assert_eq!(
+        ticket.expires_at(),
+        Utc.with_ymd_and_hms(2024, 10, 18, 21, 0, 0).unwrap()
+    );
+    assert!(ticket.is_expired());
+}
+
+#[test]
+fn test_parse_truncated_logon_ticket() {
+    let mut bytes = ticket_bytes();
+    bytes.truncate(20);
+
+    assert!(matches!(
+        LogonTicket::from_bytes(&bytes),
+        Err(LogonTicketError::Truncated)
+    ));
+    assert!(matches!(
+        "not base64!".parse::<LogonTicket>(),
+        Err(LogonTicketError::InvalidEncoding)
+    ));
+}
+
+#[test]
+fn test_parse_malformed_logon_ticket_validity() {
+    // 8바이트를 넘는 정수 필드
+    assert!(matches!(
+        LogonTicket::from_bytes(&ticket_bytes_with_validity(&[0x01; 9], &[0])),
+        Err(LogonTicketError::IntegerTooLong { length: 9, .. })
+    ));
+    // Duration으로 표현할 수 없는 유효 시간
+    assert!(matches!(
+        LogonTicket::from_bytes(&ticket_bytes_with_validity(&[0xFF; 8], &[0])),
+        Err(LogonTicketError::InvalidValidity)
+    ));
+    assert!(matches!(
+        LogonTicket::from_bytes(&ticket_bytes_with_validity(
+            &[0],
+            &(i64::MAX as u64).to_be_bytes()
+        )),
+        Err(LogonTicketError::InvalidValidity)
+    ));
+    // 기간은 표현할 수 있지만 만료 시각이 날짜 범위를 넘는 경우
+    assert!(matches!(
+        LogonTicket::from_bytes(&ticket_bytes_with_validity(
+            &(i64::MAX as u64 / 3_600_000).to_be_bytes(),
+            &[0]
+        )),
+        Err(LogonTicketError::InvalidValidity)
+    ));
+}